@@ -0,0 +1,100 @@
+use std::{fs, io, path::Path};
+
+use common::Part;
+use serde::{Deserialize, Serialize};
+
+/// The file, in each day's directory, holding the answers to its `input.txt`.
+pub const FILE: &str = "answers.toml";
+
+/// The recorded answers to a day's puzzle, kept as text so every answer type fits.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        toml::from_str(s).map_err(|e| e.to_string())
+    }
+
+    /// Reads the answers at `path`, which are empty if nothing has been recorded yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => {
+                Self::parse(&s).map_err(|e| format!("failed to parse {}: {e}", path.display()))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("failed to read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let s = toml::to_string(self).expect("answers should serialize");
+        fs::write(path, s).map_err(|e| format!("failed to write {}: {e}", path.display()))
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Check {
+        match self.get(part) {
+            Some(expected) if expected == answer => Check::Correct,
+            Some(expected) => Check::Wrong {
+                expected: expected.to_string(),
+            },
+            None => Check::Unrecorded,
+        }
+    }
+}
+
+/// How a computed answer compares to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    Unrecorded,
+    Wrong { expected: String },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("part1 = \"114\"\npart2 = \"2\"\n").unwrap();
+        assert_eq!(answers.get(Part::One), Some("114"));
+        assert_eq!(answers.check(Part::Two, "2"), Check::Correct);
+        assert_eq!(
+            answers.check(Part::Two, "3"),
+            Check::Wrong {
+                expected: "2".to_string()
+            }
+        );
+        assert_eq!(Answers::parse("").unwrap(), Answers::default());
+        assert!(Answers::parse("part1 = 114").is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.set(Part::Two, "21003205388413".to_string());
+        assert_eq!(answers.check(Part::One, "19631"), Check::Unrecorded);
+        let s = toml::to_string(&answers).unwrap();
+        assert_eq!(s, "part2 = \"21003205388413\"\n");
+        assert_eq!(Answers::parse(&s).unwrap(), answers);
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    fmt::{self, Display, Write},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use common::{ParseError, Part, Solution};
+use serde::Serialize;
+
+/// The inputs each day is benchmarked against, relative to its directory.
+pub const INPUTS: [&str; 2] = ["input.txt", "test_input.txt"];
+
+/// How many times to run each stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub samples: usize,
+    /// Sampling stops early once a stage has taken this long in total.
+    pub budget: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            samples: 10,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Stage {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Stage::Part1,
+            Part::Two => Stage::Part2,
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part 1"),
+            Stage::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+/// Runs `f` once to warm up, then samples it until `config` says to stop.
+pub fn measure<T>(config: &Config, mut f: impl FnMut() -> T) -> Timing {
+    black_box(f());
+    let mut durations = Vec::with_capacity(config.samples);
+    let mut total = Duration::ZERO;
+    while durations.len() < config.samples.max(1) && (durations.is_empty() || total < config.budget)
+    {
+        let start = Instant::now();
+        black_box(f());
+        let duration = start.elapsed();
+        durations.push(duration);
+        total += duration;
+    }
+    durations.sort();
+    Timing {
+        samples: durations.len(),
+        min: durations[0],
+        median: durations[durations.len() / 2],
+        mean: total / durations.len() as u32,
+    }
+}
+
+/// Times parsing `input` and solving each of `parts` from the parsed input.
+pub fn bench<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    config: &Config,
+) -> Result<Vec<(Stage, Timing)>, ParseError> {
+    let parsed = S::parse(input)?;
+    let mut timings = vec![(Stage::Parse, measure(config, || S::parse(input)))];
+    for &part in parts {
+        timings.push((part.into(), measure(config, || S::solve(&parsed, part))));
+    }
+    Ok(timings)
+}
+
+/// A row of the summary: a stage's timing, or why it was skipped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Row {
+    pub day: u8,
+    pub input: String,
+    pub stage: Stage,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub samples: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_ns: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub median_ns: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mean_ns: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped: Option<String>,
+}
+
+impl Row {
+    pub fn timed(day: u8, input: &str, stage: Stage, timing: Timing) -> Self {
+        Self {
+            day,
+            input: input.to_string(),
+            stage,
+            samples: Some(timing.samples),
+            min_ns: Some(timing.min.as_nanos()),
+            median_ns: Some(timing.median.as_nanos()),
+            mean_ns: Some(timing.mean.as_nanos()),
+            skipped: None,
+        }
+    }
+
+    pub fn skipped(day: u8, input: &str, stage: Stage, reason: impl Into<String>) -> Self {
+        Self {
+            day,
+            input: input.to_string(),
+            stage,
+            samples: None,
+            min_ns: None,
+            median_ns: None,
+            mean_ns: None,
+            skipped: Some(reason.into()),
+        }
+    }
+}
+
+fn format_duration(nanos: Option<u128>) -> String {
+    let Some(nanos) = nanos else {
+        return "-".to_string();
+    };
+    let nanos = nanos as f64;
+    if nanos < 1e3 {
+        format!("{nanos} ns")
+    } else if nanos < 1e6 {
+        format!("{:.2} µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2} ms", nanos / 1e6)
+    } else {
+        format!("{:.2} s", nanos / 1e9)
+    }
+}
+
+pub fn markdown(rows: &[Row]) -> String {
+    let mut table = String::new();
+    table.push_str("| day | input | stage | samples | median | min | mean | notes |\n");
+    table.push_str("|---|---|---|---:|---:|---:|---:|---|\n");
+    for row in rows {
+        writeln!(
+            table,
+            "| {:02} | {} | {} | {} | {} | {} | {} | {} |",
+            row.day,
+            row.input,
+            row.stage,
+            row.samples.map_or("-".to_string(), |s| s.to_string()),
+            format_duration(row.median_ns),
+            format_duration(row.min_ns),
+            format_duration(row.mean_ns),
+            row.skipped
+                .as_deref()
+                .map_or(String::new(), |r| format!("skipped: {r}")),
+        )
+        .expect("writing to a string can't fail");
+    }
+    table
+}
+
+pub fn json(rows: &[Row]) -> String {
+    serde_json::to_string_pretty(rows).expect("rows should serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let config = Config {
+            samples: 5,
+            budget: Duration::from_secs(1),
+        };
+        let mut calls = 0;
+        let timing = measure(&config, || calls += 1);
+        assert_eq!(timing.samples, 5);
+        assert_eq!(calls, 6);
+        assert!(timing.min <= timing.median);
+
+        let config = Config {
+            samples: 5,
+            budget: Duration::ZERO,
+        };
+        assert_eq!(measure(&config, || ()).samples, 1);
+    }
+
+    #[test]
+    fn test_summary() {
+        let timing = Timing {
+            samples: 3,
+            min: Duration::from_micros(1500),
+            median: Duration::from_micros(2000),
+            mean: Duration::from_nanos(2_100_000),
+        };
+        let rows = vec![
+            Row::timed(9, "input.txt", Stage::Part1, timing),
+            Row::skipped(5, "input.txt", Stage::Part2, "too slow"),
+        ];
+        let table = markdown(&rows);
+        assert!(table.contains("| 09 | input.txt | part 1 | 3 | 2.00 ms | 1.50 ms | 2.10 ms |  |"));
+        assert!(table.contains("| 05 | input.txt | part 2 | - | - | - | - | skipped: too slow |"));
+
+        let json: serde_json::Value = serde_json::from_str(&json(&rows)).unwrap();
+        assert_eq!(json[0]["stage"], "part1");
+        assert_eq!(json[0]["median_ns"], 2_000_000);
+        assert_eq!(json[1]["skipped"], "too slow");
+        assert!(json[1].get("samples").is_none());
+    }
+}
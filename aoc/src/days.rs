@@ -0,0 +1,80 @@
+use std::path::{Path, PathBuf};
+
+use common::{ParseError, Part, Report, Solution};
+
+use crate::bench::{self, Stage, Timing};
+
+pub const DAYS: u8 = 21;
+
+/// Parses the input once and solves each of `parts`, erasing the answer type.
+pub type Runner = fn(&str, &[Part]) -> Result<Vec<Report>, ParseError>;
+
+/// Times parsing and solving each of the parts, erasing the day's types.
+pub type Bencher = fn(&str, &[Part], &bench::Config) -> Result<Vec<(Stage, Timing)>, ParseError>;
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub run: Runner,
+    pub bench: Bencher,
+}
+
+impl Day {
+    fn of<S: Solution>() -> Self {
+        Self {
+            run: common::report::solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+}
+
+/// The directory of a day's crate, where its inputs are kept.
+pub fn dir(day: u8) -> PathBuf {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner is in the workspace");
+    workspace.join(format!("day{day:02}"))
+}
+
+/// The answers committed for a day, which are checked even when its input comes from the
+/// cache.
+pub fn answers(day: u8) -> PathBuf {
+    dir(day).join(crate::answers::FILE)
+}
+
+/// Parts that can't be run on an input in a reasonable time or memory, and why.
+const SKIPPED: [(u8, Part, &str, &str); 0] = [];
+
+pub fn skip_reason(day: u8, part: Part, input: &str) -> Option<&'static str> {
+    SKIPPED
+        .iter()
+        .find(|&&(d, p, i, _)| d == day && p == part && i == input)
+        .map(|&(_, _, _, reason)| reason)
+}
+
+pub fn get(day: u8) -> Option<Day> {
+    let day = match day {
+        1 => Day::of::<day01::Day01>(),
+        2 => Day::of::<day02::Day02>(),
+        3 => Day::of::<day03::Day03>(),
+        4 => Day::of::<day04::Day04>(),
+        5 => Day::of::<day05::Day05>(),
+        6 => Day::of::<day06::Day06>(),
+        7 => Day::of::<day07::Day07>(),
+        8 => Day::of::<day08::Day08>(),
+        9 => Day::of::<day09::Day09>(),
+        10 => Day::of::<day10::Day10>(),
+        11 => Day::of::<day11::Day11>(),
+        12 => Day::of::<day12::Day12>(),
+        13 => Day::of::<day13::Day13>(),
+        14 => Day::of::<day14::Day14>(),
+        15 => Day::of::<day15::Day15>(),
+        16 => Day::of::<day16::Day16>(),
+        17 => Day::of::<day17::Day17>(),
+        18 => Day::of::<day18::Day18>(),
+        19 => Day::of::<day19::Day19>(),
+        20 => Day::of::<day20::Day20>(),
+        21 => Day::of::<day21::Day21>(),
+        _ => return None,
+    };
+    Some(day)
+}
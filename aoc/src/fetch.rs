@@ -0,0 +1,285 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use common::input::{self, YEAR};
+
+/// Where puzzle inputs are downloaded from.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The environment variable holding the session cookie used to download inputs.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The shortest time to leave between requests, so as to go easy on the server.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Records when the last request was made, in the root of the cache, so the rate limit
+/// holds across runs.
+const LAST_REQUEST_FILE: &str = ".last-request";
+
+const USER_AGENT: &str = "github.com/BradLewis/advent-of-code-2023 input fetcher";
+
+#[derive(Debug)]
+pub enum FetchError {
+    Status(u16, String),
+    Transport(String),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::Status(status, body) => {
+                write!(f, "server responded with {status}: {}", body.trim())
+            }
+            FetchError::Transport(e) => write!(f, "request failed: {e}"),
+            FetchError::Io(path, e) => write!(f, "failed to write {}: {e}", path.display()),
+        }
+    }
+}
+
+/// The HTTP client used to download inputs.
+pub trait Http {
+    /// Gets `url` with `session` as the session cookie, returning the body of the response.
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError>;
+}
+
+pub struct Ureq {
+    agent: ureq::Agent,
+}
+
+impl Default for Ureq {
+    fn default() -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(USER_AGENT)
+            .build();
+        Self { agent }
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={session}"))
+            .call();
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| FetchError::Transport(e.to_string())),
+            Err(ureq::Error::Status(status, response)) => Err(FetchError::Status(
+                status,
+                response.into_string().unwrap_or_default(),
+            )),
+            Err(ureq::Error::Transport(e)) => Err(FetchError::Transport(e.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads inputs into the cache, never fetching one that is already there.
+pub struct Fetcher<H> {
+    http: H,
+    cache: PathBuf,
+    session: String,
+    base_url: String,
+    min_interval: Duration,
+}
+
+impl<H: Http> Fetcher<H> {
+    pub fn new(http: H, cache: PathBuf, session: String) -> Self {
+        Self {
+            http,
+            cache,
+            session,
+            base_url: BASE_URL.to_string(),
+            min_interval: MIN_INTERVAL,
+        }
+    }
+
+    /// The input for `day`, downloading it first if it isn't cached.
+    pub fn fetch(&self, day: u8) -> Result<Fetched, FetchError> {
+        let dir = input::cache_dir(&self.cache, day);
+        let path = dir.join(input::file_name("input"));
+        if path.is_file() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        self.wait()?;
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        let body = self.http.get(&url, &self.session);
+        self.record_request()?;
+        let body = body?;
+
+        // write to a temporary file first, so an interrupted write never looks cached
+        fs::create_dir_all(&dir).map_err(|e| FetchError::Io(dir.clone(), e))?;
+        let partial = path.with_extension("partial");
+        fs::write(&partial, body).map_err(|e| FetchError::Io(partial.clone(), e))?;
+        fs::rename(&partial, &path).map_err(|e| FetchError::Io(path.clone(), e))?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request.
+    fn wait(&self) -> Result<(), FetchError> {
+        let Ok(last) = fs::read_to_string(self.cache.join(LAST_REQUEST_FILE)) else {
+            return Ok(());
+        };
+        let Ok(last) = last.trim().parse::<u64>() else {
+            return Ok(());
+        };
+        let last = UNIX_EPOCH + Duration::from_millis(last);
+        let elapsed = SystemTime::now()
+            .duration_since(last)
+            .unwrap_or(Duration::ZERO);
+        if let Some(remaining) = self.min_interval.checked_sub(elapsed) {
+            thread::sleep(remaining);
+        }
+        Ok(())
+    }
+
+    fn record_request(&self) -> Result<(), FetchError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("the clock is after 1970")
+            .as_millis();
+        write_file(&self.cache, LAST_REQUEST_FILE, &now.to_string())
+    }
+}
+
+fn write_file(dir: &Path, name: &str, contents: &str) -> Result<(), FetchError> {
+    fs::create_dir_all(dir).map_err(|e| FetchError::Io(dir.to_path_buf(), e))?;
+    let path = dir.join(name);
+    fs::write(&path, contents).map_err(|e| FetchError::Io(path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        cell::RefCell,
+        env,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        time::Instant,
+    };
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Serves `responses` in order, one per connection, recording the path and cookie of
+    /// each request.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    let lower = line.to_lowercase();
+                    if lower.starts_with("get ") || lower.starts_with("cookie:") {
+                        request.push_str(line.trim());
+                        request.push(' ');
+                    }
+                }
+                seen.lock().unwrap().push(request.trim().to_string());
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (url, requests) = serve(vec![(200, "1 2 3\n")]);
+        let cache = temp_dir("once");
+        let fetcher = Fetcher {
+            base_url: url,
+            min_interval: Duration::ZERO,
+            ..Fetcher::new(Ureq::default(), cache.clone(), "abc".to_string())
+        };
+
+        let path = input::cache_dir(&cache, 9).join("input.txt");
+        assert_eq!(fetcher.fetch(9).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+        assert_eq!(fetcher.fetch(9).unwrap(), Fetched::Cached(path));
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec!["GET /2023/day/9/input HTTP/1.1 Cookie: session=abc"]
+        );
+        fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_error() {
+        let (url, _) = serve(vec![(400, "Puzzle inputs differ by user.")]);
+        let cache = temp_dir("error");
+        let fetcher = Fetcher {
+            base_url: url,
+            min_interval: Duration::ZERO,
+            ..Fetcher::new(Ureq::default(), cache.clone(), "bad".to_string())
+        };
+
+        let error = fetcher.fetch(1).unwrap_err();
+        assert!(matches!(error, FetchError::Status(400, _)), "{error}");
+        assert!(!input::cache_dir(&cache, 1).join("input.txt").exists());
+        fs::remove_dir_all(&cache).unwrap();
+    }
+
+    struct Clock(RefCell<Vec<Instant>>);
+
+    impl Http for Clock {
+        fn get(&self, _url: &str, _session: &str) -> Result<String, FetchError> {
+            self.0.borrow_mut().push(Instant::now());
+            Ok(String::new())
+        }
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let cache = temp_dir("rate");
+        let interval = Duration::from_millis(200);
+        let fetcher = Fetcher {
+            min_interval: interval,
+            ..Fetcher::new(
+                Clock(RefCell::new(Vec::new())),
+                cache.clone(),
+                String::new(),
+            )
+        };
+
+        fetcher.fetch(1).unwrap();
+        fetcher.fetch(2).unwrap();
+        let times = fetcher.http.0.borrow();
+        assert_eq!(times.len(), 2);
+        assert!(times[1] - times[0] >= interval - Duration::from_millis(5));
+        fs::remove_dir_all(&cache).unwrap();
+    }
+}
@@ -0,0 +1,493 @@
+use std::{env, ops::RangeInclusive, process, str::FromStr};
+
+use common::{input::Source, report, Format, Part};
+
+mod answers;
+mod bench;
+mod days;
+mod fetch;
+
+const USAGE: &str =
+    "usage: aoc run [DAY] [--part PART] [--input PATH|-] [--example NAME] [--format text|json]
+       aoc bench [DAY] [--part PART] [--samples N] [--format markdown|json]
+       aoc verify [DAY] [--part PART] [--record]
+       aoc fetch [DAY] [--session TOKEN]";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct RunArgs {
+    day: Option<u8>,
+    part: Option<Part>,
+    input: Option<Source>,
+    example: Option<String>,
+    format: Format,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut run_args = RunArgs::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args.next().ok_or("--part expects a value")?;
+                run_args.part = Some(part.parse()?);
+            }
+            "--input" | "-i" => {
+                let input = args.next().ok_or("--input expects a path")?;
+                run_args.input = Some(Source::from_arg(input));
+            }
+            "--example" | "-e" => {
+                let example = args.next().ok_or("--example expects a name")?;
+                run_args.example = Some(common::input::example_name(example));
+            }
+            "--format" | "-f" => {
+                let format = args.next().ok_or("--format expects a value")?;
+                run_args.format = format.parse()?;
+            }
+            day if run_args.day.is_none() => run_args.day = Some(parse_day(day)?),
+            other => return Err(format!("unexpected argument: {other}")),
+        }
+    }
+    if run_args.input.is_some() && run_args.example.is_some() {
+        return Err("--input and --example can't be used together".to_string());
+    }
+    if run_args.input.is_some() && run_args.day.is_none() {
+        return Err("--input requires a day".to_string());
+    }
+    Ok(run_args)
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct VerifyArgs {
+    day: Option<u8>,
+    part: Option<Part>,
+    record: bool,
+}
+
+fn parse_verify_args(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut verify_args = VerifyArgs::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args.next().ok_or("--part expects a value")?;
+                verify_args.part = Some(part.parse()?);
+            }
+            "--record" => verify_args.record = true,
+            day if verify_args.day.is_none() => verify_args.day = Some(parse_day(day)?),
+            other => return Err(format!("unexpected argument: {other}")),
+        }
+    }
+    Ok(verify_args)
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct FetchArgs {
+    day: Option<u8>,
+    session: Option<String>,
+}
+
+fn parse_fetch_args(args: &[String]) -> Result<FetchArgs, String> {
+    let mut fetch_args = FetchArgs::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--session" | "-s" => {
+                let session = args.next().ok_or("--session expects a token")?;
+                fetch_args.session = Some(session.to_string());
+            }
+            day if fetch_args.day.is_none() => fetch_args.day = Some(parse_day(day)?),
+            other => return Err(format!("unexpected argument: {other}")),
+        }
+    }
+    Ok(fetch_args)
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    match day.parse::<u8>() {
+        Ok(day @ 1..=days::DAYS) => Ok(day),
+        _ => Err(format!("invalid day: {day}")),
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum SummaryFormat {
+    #[default]
+    Markdown,
+    Json,
+}
+
+impl FromStr for SummaryFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "markdown" | "md" => Ok(SummaryFormat::Markdown),
+            "json" => Ok(SummaryFormat::Json),
+            _ => Err(format!("invalid format: {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct BenchArgs {
+    day: Option<u8>,
+    part: Option<Part>,
+    config: bench::Config,
+    format: SummaryFormat,
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args.next().ok_or("--part expects a value")?;
+                bench_args.part = Some(part.parse()?);
+            }
+            "--samples" | "-n" => {
+                let samples = args.next().ok_or("--samples expects a number")?;
+                bench_args.config.samples = match samples.parse() {
+                    Ok(samples) if samples > 0 => samples,
+                    _ => return Err(format!("invalid sample count: {samples}")),
+                };
+            }
+            "--format" | "-f" => {
+                let format = args.next().ok_or("--format expects a value")?;
+                bench_args.format = format.parse()?;
+            }
+            day if bench_args.day.is_none() => bench_args.day = Some(parse_day(day)?),
+            other => return Err(format!("unexpected argument: {other}")),
+        }
+    }
+    Ok(bench_args)
+}
+
+/// The days and parts a command works on: the ones given, or all of them.
+fn selection(day: Option<u8>, part: Option<Part>) -> (RangeInclusive<u8>, Vec<Part>) {
+    let days = match day {
+        Some(day) => day..=day,
+        None => 1..=days::DAYS,
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    (days, parts)
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let (days, parts) = selection(args.day, args.part);
+    let mut all_reports = Vec::new();
+    for day in days {
+        let source = match (&args.input, &args.example) {
+            (Some(source), _) => source.clone(),
+            (None, Some(example)) => Source::named(day, &days::dir(day), example),
+            (None, None) => Source::named(day, &days::dir(day), "input"),
+        };
+        let input = source.read()?;
+        let path = source.to_string();
+        let solution = days::get(day).ok_or(format!("day {day} has not been solved"))?;
+        let reports = (solution.run)(&input, &parts).map_err(|e| e.render(&path, &input))?;
+        match args.format {
+            Format::Text => {
+                for report in &reports {
+                    println!("day {day:02} part {}: {}", report.part, report.answer);
+                }
+            }
+            Format::Json => all_reports.extend(reports),
+        }
+    }
+    if args.format == Format::Json {
+        println!("{}", report::to_json(&all_reports));
+    }
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let (days, parts) = selection(args.day, args.part);
+    let mut rows = Vec::new();
+    for day in days {
+        let solution = days::get(day).ok_or(format!("day {day} has not been solved"))?;
+        for name in bench::INPUTS {
+            let source = Source::named(day, &days::dir(day), name);
+            let path = source.to_string();
+            let input = match source.read() {
+                Ok(input) => input,
+                Err(e) => {
+                    rows.push(bench::Row::skipped(day, name, bench::Stage::Parse, e));
+                    continue;
+                }
+            };
+            eprintln!("benchmarking {path}");
+            let (skipped, included): (Vec<Part>, Vec<Part>) = parts
+                .iter()
+                .partition(|&&part| days::skip_reason(day, part, name).is_some());
+            let timings = (solution.bench)(&input, &included, &args.config)
+                .map_err(|e| e.render(&path, &input))?;
+            rows.extend(
+                timings
+                    .into_iter()
+                    .map(|(stage, timing)| bench::Row::timed(day, name, stage, timing)),
+            );
+            for part in skipped {
+                let reason = days::skip_reason(day, part, name).expect("partitioned on reason");
+                rows.push(bench::Row::skipped(day, name, part.into(), reason));
+            }
+        }
+    }
+    match args.format {
+        SummaryFormat::Markdown => print!("{}", bench::markdown(&rows)),
+        SummaryFormat::Json => println!("{}", bench::json(&rows)),
+    }
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), String> {
+    let (days, parts) = selection(args.day, args.part);
+    let mut mismatches = 0;
+    for day in days {
+        let solution = days::get(day).ok_or(format!("day {day} has not been solved"))?;
+        let source = Source::named(day, &days::dir(day), "input");
+        let input = source.read()?;
+        let path = source.to_string();
+        let answers_path = days::answers(day);
+        let mut answers = answers::Answers::load(&answers_path)?;
+
+        let included: Vec<Part> = parts
+            .iter()
+            .copied()
+            .filter(|&part| days::skip_reason(day, part, "input.txt").is_none())
+            .collect();
+        let mut results = (solution.run)(&input, &included)
+            .map_err(|e| e.render(&path, &input))?
+            .into_iter();
+        let mut changed = false;
+        for &part in &parts {
+            if let Some(reason) = days::skip_reason(day, part, "input.txt") {
+                println!("day {day:02} part {part}: skipped, {reason}");
+                continue;
+            }
+            let answer = results.next().expect("one answer per included part").answer;
+            let status = match answers.check(part, &answer) {
+                answers::Check::Correct => "ok".to_string(),
+                answers::Check::Unrecorded if args.record => "recorded".to_string(),
+                answers::Check::Unrecorded => "unrecorded".to_string(),
+                answers::Check::Wrong { expected } if args.record => {
+                    format!("recorded, was {expected}")
+                }
+                answers::Check::Wrong { expected } => {
+                    mismatches += 1;
+                    format!("MISMATCH, expected {expected}")
+                }
+            };
+            println!("day {day:02} part {part}: {answer} ({status})");
+            if args.record && answers.get(part) != Some(answer.as_str()) {
+                answers.set(part, answer);
+                changed = true;
+            }
+        }
+        if changed {
+            answers.save(&answers_path)?;
+        }
+    }
+    match mismatches {
+        0 => Ok(()),
+        1 => Err("1 answer didn't match".to_string()),
+        n => Err(format!("{n} answers didn't match")),
+    }
+}
+
+fn fetch(args: FetchArgs) -> Result<(), String> {
+    let (days, _) = selection(args.day, None);
+    let session = match args.session {
+        Some(session) => session,
+        None => env::var(fetch::SESSION_VAR).map_err(|_| {
+            format!(
+                "no session token, set {} or pass --session",
+                fetch::SESSION_VAR
+            )
+        })?,
+    };
+    let cache = common::input::cache_root().ok_or(format!(
+        "couldn't find a cache directory, set {}",
+        common::input::CACHE_VAR
+    ))?;
+    let fetcher = fetch::Fetcher::new(fetch::Ureq::default(), cache, session.trim().to_string());
+    for day in days {
+        match fetcher
+            .fetch(day)
+            .map_err(|e| format!("day {day:02}: {e}"))?
+        {
+            fetch::Fetched::Cached(path) => {
+                println!("day {day:02}: already cached at {}", path.display())
+            }
+            fetch::Fetched::Downloaded(path) => {
+                println!("day {day:02}: downloaded to {}", path.display())
+            }
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.split_first() {
+        Some((command, rest)) if command == "run" => parse_run_args(rest).and_then(run),
+        Some((command, rest)) if command == "bench" => parse_bench_args(rest).and_then(bench),
+        Some((command, rest)) if command == "verify" => parse_verify_args(rest).and_then(verify),
+        Some((command, rest)) if command == "fetch" => parse_fetch_args(rest).and_then(fetch),
+        _ => Err(USAGE.to_string()),
+    };
+    if let Err(e) = result {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_run_args() {
+        let result = parse_run_args(&args(&["17", "--part", "2", "--input", "in.txt"]));
+        assert_eq!(
+            result,
+            Ok(RunArgs {
+                day: Some(17),
+                part: Some(Part::Two),
+                input: Some(Source::from_arg("in.txt")),
+                example: None,
+                format: Format::Text,
+            })
+        );
+
+        assert_eq!(parse_run_args(&args(&[])), Ok(RunArgs::default()));
+        assert!(parse_run_args(&args(&["22"])).is_err());
+        assert!(parse_run_args(&args(&["1", "--part", "3"])).is_err());
+        assert!(parse_run_args(&args(&["--input", "in.txt"])).is_err());
+        assert_eq!(
+            parse_run_args(&args(&["8", "--example", "3"])).map(|a| a.example),
+            Ok(Some("test_input3".to_string()))
+        );
+        assert_eq!(
+            parse_run_args(&args(&["8", "-i", "-"])).map(|a| a.input),
+            Ok(Some(Source::Stdin))
+        );
+        assert!(parse_run_args(&args(&["8", "-i", "-", "-e", "2"])).is_err());
+    }
+
+    #[test]
+    fn test_runner() {
+        let input = fs::read_to_string("../day09/test_input.txt").expect("failed to read input");
+        let runner = days::get(9).expect("day 9 should be solved").run;
+        let reports = runner(&input, &Part::ALL).unwrap();
+        let answers: Vec<_> = reports
+            .iter()
+            .map(|r| (r.day, r.part, r.answer.as_str()))
+            .collect();
+        assert_eq!(answers, vec![(9, Part::One, "114"), (9, Part::Two, "2")]);
+        assert_eq!(runner("0 3 x", &Part::ALL).unwrap_err().column(), 5);
+        assert!(days::get(22).is_none());
+
+        // day 20's example has no `rx` module, which part 2 now answers as never
+        assert!(days::skip_reason(20, Part::Two, "test_input.txt").is_none());
+    }
+
+    #[test]
+    fn test_selection() {
+        assert_eq!(selection(Some(8), None), (8..=8, Part::ALL.to_vec()));
+        assert_eq!(
+            selection(None, Some(Part::Two)),
+            (1..=days::DAYS, vec![Part::Two])
+        );
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        let result = parse_bench_args(&args(&["5", "--samples", "3", "--format", "json"]));
+        assert_eq!(
+            result,
+            Ok(BenchArgs {
+                day: Some(5),
+                part: None,
+                config: bench::Config {
+                    samples: 3,
+                    ..Default::default()
+                },
+                format: SummaryFormat::Json,
+            })
+        );
+
+        assert_eq!(parse_bench_args(&args(&[])), Ok(BenchArgs::default()));
+        assert!(parse_bench_args(&args(&["--samples", "0"])).is_err());
+        assert!(parse_bench_args(&args(&["--format", "csv"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_verify_args() {
+        let result = parse_verify_args(&args(&["--record", "8", "-p", "1"]));
+        assert_eq!(
+            result,
+            Ok(VerifyArgs {
+                day: Some(8),
+                part: Some(Part::One),
+                record: true,
+            })
+        );
+
+        assert_eq!(parse_verify_args(&args(&[])), Ok(VerifyArgs::default()));
+        assert!(parse_verify_args(&args(&["8", "9"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_fetch_args() {
+        assert_eq!(
+            parse_fetch_args(&args(&["3", "--session", "abc"])),
+            Ok(FetchArgs {
+                day: Some(3),
+                session: Some("abc".to_string()),
+            })
+        );
+        assert_eq!(parse_fetch_args(&args(&[])), Ok(FetchArgs::default()));
+        assert!(parse_fetch_args(&args(&["--session"])).is_err());
+    }
+
+    #[test]
+    fn test_bench() {
+        let input = fs::read_to_string("../day09/test_input.txt").expect("failed to read input");
+        let bencher = days::get(9).expect("day 9 should be solved").bench;
+        let config = bench::Config {
+            samples: 2,
+            ..Default::default()
+        };
+        let timings = bencher(&input, &[Part::Two], &config).unwrap();
+        let stages: Vec<_> = timings.iter().map(|&(stage, _)| stage).collect();
+        assert_eq!(stages, vec![bench::Stage::Parse, bench::Stage::Part2]);
+        assert!(timings.iter().all(|(_, timing)| timing.samples == 2));
+    }
+
+    #[test]
+    fn test_answers_with_cached_input() {
+        let cache = env::temp_dir().join(format!("aoc-verify-{}", process::id()));
+        let cached = common::input::cache_dir(&cache, 2);
+        fs::create_dir_all(&cached).expect("failed to create cache");
+        fs::write(cached.join("input.txt"), "Game 1: 1 red\n").expect("failed to cache input");
+        env::set_var(common::input::CACHE_VAR, &cache);
+
+        let source = Source::named(2, &days::dir(2), "input");
+        assert_eq!(source, Source::File(cached.join("input.txt")));
+        assert_eq!(days::answers(2), days::dir(2).join(answers::FILE));
+        let answers = answers::Answers::load(&days::answers(2)).unwrap();
+        assert_eq!(answers.get(Part::One), Some("1734"));
+
+        env::remove_var(common::input::CACHE_VAR);
+        fs::remove_dir_all(&cache).expect("failed to remove cache");
+    }
+}
@@ -0,0 +1,261 @@
+use std::cmp::Ordering;
+
+use crate::ParseError;
+
+/// A compass direction, with north pointing up the page, towards smaller `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_left().turn_left()
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::East | Direction::West)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// The change in `x` and `y` from taking one step this way.
+    pub fn delta(self) -> (i64, i64) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+
+    /// Reads `U`, `D`, `L` or `R`.
+    pub fn from_letter(c: char) -> Result<Self, ParseError> {
+        match c {
+            'U' => Ok(Direction::North),
+            'R' => Ok(Direction::East),
+            'D' => Ok(Direction::South),
+            'L' => Ok(Direction::West),
+            _ => Err(ParseError::new("invalid direction", c)),
+        }
+    }
+
+    /// Reads a digit from `0` to `3`, meaning right, down, left and up.
+    pub fn from_digit(c: char) -> Result<Self, ParseError> {
+        match c {
+            '0' => Ok(Direction::East),
+            '1' => Ok(Direction::South),
+            '2' => Ok(Direction::West),
+            '3' => Ok(Direction::North),
+            _ => Err(ParseError::new("invalid direction", c)),
+        }
+    }
+}
+
+/// A turn to the left or right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Turn {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(Turn::Left),
+            'R' => Ok(Turn::Right),
+            _ => Err(ParseError::new("invalid turn", c)),
+        }
+    }
+}
+
+/// A point that can't go below zero, such as a position in a grid. Positions are ordered row
+/// by row, in reading order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Ord for Position {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Position {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Position {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Moves one step in `direction`, unless that would go below zero.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        self.step_by(direction, 1)
+    }
+
+    /// Moves `distance` steps in `direction`, unless that would go below zero.
+    pub fn step_by(self, direction: Direction, distance: usize) -> Option<Self> {
+        let (dx, dy) = direction.delta();
+        Some(Self {
+            x: offset(self.x, dx, distance)?,
+            y: offset(self.y, dy, distance)?,
+        })
+    }
+
+    /// Moves one step in `direction`, unless that would leave a `width` by `height` area.
+    pub fn step_within(self, direction: Direction, width: usize, height: usize) -> Option<Self> {
+        self.step(direction).filter(|p| p.x < width && p.y < height)
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+fn offset(n: usize, delta: i64, distance: usize) -> Option<usize> {
+    match delta {
+        1 => n.checked_add(distance),
+        -1 => n.checked_sub(distance),
+        _ => Some(n),
+    }
+}
+
+/// A point anywhere on the plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Self {
+        self.step_by(direction, 1)
+    }
+
+    pub fn step_by(self, direction: Direction, distance: i64) -> Self {
+        let (dx, dy) = direction.delta();
+        Self {
+            x: self.x + dx * distance,
+            y: self.y + dy * distance,
+        }
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl From<Position> for Point {
+    fn from(position: Position) -> Self {
+        Self::new(position.x as i64, position.y as i64)
+    }
+}
+
+impl TryFrom<Point> for Position {
+    type Error = ();
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        let x = usize::try_from(point.x).map_err(|_| ())?;
+        let y = usize::try_from(point.y).map_err(|_| ())?;
+        Ok(Self { x, y })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turning() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_ne!(direction.reverse(), direction);
+        }
+        assert_eq!(Direction::North.turn(Turn::Right), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.reverse(), Direction::West);
+    }
+
+    #[test]
+    fn test_stepping() {
+        let origin = Position::new(0, 0);
+        assert_eq!(origin.step(Direction::North), None);
+        assert_eq!(origin.step(Direction::East), Some(Position::new(1, 0)));
+        assert_eq!(
+            origin.step_by(Direction::South, 3),
+            Some(Position::new(0, 3))
+        );
+        assert_eq!(Position::new(1, 1).step_within(Direction::East, 2, 2), None);
+        assert_eq!(
+            Point::new(0, 0).step_by(Direction::North, 2),
+            Point::new(0, -2)
+        );
+        assert_eq!(Position::try_from(Point::new(-1, 0)), Err(()));
+        assert!(Position::new(5, 0) < Position::new(0, 1));
+    }
+
+    #[test]
+    fn test_manhattan() {
+        assert_eq!(Position::new(1, 6).manhattan(Position::new(5, 11)), 9);
+        assert_eq!(Point::new(-2, 3).manhattan(Point::new(1, -1)), 7);
+    }
+
+    #[test]
+    fn test_encodings() {
+        assert_eq!(Direction::from_letter('U'), Ok(Direction::North));
+        assert_eq!(Direction::from_digit('0'), Ok(Direction::East));
+        assert!(Direction::from_letter('X').is_err());
+        assert_eq!(Turn::try_from('L'), Ok(Turn::Left));
+    }
+}
@@ -0,0 +1,196 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// The environment variable naming a directory of inputs, laid out as `day01/input.txt`,
+/// `day01/test_input.txt` and so on, which is used instead of each day's own directory.
+pub const ROOT_VAR: &str = "AOC_INPUTS";
+
+/// The puzzle year, which the input cache is keyed by.
+pub const YEAR: u16 = 2023;
+
+/// The environment variable naming the input cache, which otherwise defaults to
+/// `$XDG_CACHE_HOME/aoc` or `~/.cache/aoc`.
+pub const CACHE_VAR: &str = "AOC_CACHE";
+
+/// The directory fetched inputs are cached in, if one can be found.
+pub fn cache_root() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os(CACHE_VAR) {
+        return Some(dir.into());
+    }
+    if let Some(dir) = env::var_os("XDG_CACHE_HOME") {
+        return Some(PathBuf::from(dir).join("aoc"));
+    }
+    env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache").join("aoc"))
+}
+
+/// Where the cache under `root` keeps the inputs for `day`, which is `<root>/<year>/dayNN`.
+pub fn cache_dir(root: &Path, day: u8) -> PathBuf {
+    root.join(YEAR.to_string()).join(format!("day{day:02}"))
+}
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// A path given on the command line, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    /// The input called `name`, such as `input` or `test_input2`, for `day`.
+    ///
+    /// It is looked for under the directory in `AOC_INPUTS` if that is set. Otherwise the
+    /// cached copy is used if the input has been fetched, falling back to `dir`, the day's own
+    /// directory.
+    pub fn named(day: u8, dir: &Path, name: &str) -> Self {
+        let root = env::var_os(ROOT_VAR).map(PathBuf::from);
+        Self::named_under(day, dir, root.as_deref(), cache_root().as_deref(), name)
+    }
+
+    fn named_under(
+        day: u8,
+        dir: &Path,
+        root: Option<&Path>,
+        cache: Option<&Path>,
+        name: &str,
+    ) -> Self {
+        let file = file_name(name);
+        if let Some(root) = root {
+            return Source::File(root.join(format!("day{day:02}")).join(file));
+        }
+        let cached = cache.map(|cache| cache_dir(cache, day).join(&file));
+        match cached {
+            Some(path) if path.is_file() => Source::File(path),
+            _ => Source::File(dir.join(file)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("failed to read stdin: {e}"))?;
+                Ok(input)
+            }
+            Source::File(path) => fs::read_to_string(path)
+                .map_err(|e| format!("failed to read {}: {e}", path.display())),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// The file holding the input called `name`, which may already end in `.txt`.
+pub fn file_name(name: &str) -> String {
+    if name.ends_with(".txt") {
+        name.to_string()
+    } else {
+        format!("{name}.txt")
+    }
+}
+
+/// The name of an example input, where `1` means `test_input`, `2` means `test_input2` and so
+/// on. Any other name is used as it is.
+pub fn example_name(example: &str) -> String {
+    match example.parse::<usize>() {
+        Ok(1) => "test_input".to_string(),
+        Ok(n) => format!("test_input{n}"),
+        Err(_) => example.to_string(),
+    }
+}
+
+/// Picks the input from a day's command line, which is either a path, `-` for stdin, or
+/// `--example NAME`. With neither, it is the day's `input.txt`.
+pub fn from_args(day: u8, dir: &Path, args: &[String]) -> Result<Source, String> {
+    let mut source = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let next = match arg.as_str() {
+            "--example" | "-e" => {
+                let example = args.next().ok_or("--example expects a name")?;
+                Source::named(day, dir, &example_name(example))
+            }
+            path => Source::from_arg(path),
+        };
+        if source.replace(next).is_some() {
+            return Err("expected at most one input".to_string());
+        }
+    }
+    Ok(source.unwrap_or_else(|| Source::named(day, dir, "input")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named() {
+        let dir = Path::new("day08");
+        assert_eq!(
+            Source::named_under(8, dir, None, None, "test_input2"),
+            Source::File(PathBuf::from("day08/test_input2.txt"))
+        );
+        assert_eq!(
+            Source::named_under(8, dir, Some(Path::new("/inputs")), None, "input.txt"),
+            Source::File(PathBuf::from("/inputs/day08/input.txt"))
+        );
+
+        let cache = env::temp_dir().join(format!("aoc-input-test-{}", std::process::id()));
+        let cached = cache_dir(&cache, 8);
+        fs::create_dir_all(&cached).unwrap();
+        fs::write(cached.join("input.txt"), "").unwrap();
+        assert_eq!(
+            Source::named_under(8, dir, None, Some(&cache), "input"),
+            Source::File(cached.join("input.txt"))
+        );
+        assert_eq!(
+            Source::named_under(8, dir, None, Some(&cache), "test_input"),
+            Source::File(PathBuf::from("day08/test_input.txt"))
+        );
+        fs::remove_dir_all(&cache).unwrap();
+
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(Source::from_arg("-").to_string(), "<stdin>");
+    }
+
+    #[test]
+    fn test_example_name() {
+        assert_eq!(example_name("1"), "test_input");
+        assert_eq!(example_name("3"), "test_input3");
+        assert_eq!(example_name("test_input2"), "test_input2");
+    }
+
+    #[test]
+    fn test_from_args() {
+        let args = |args: &[&str]| args.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            from_args(1, Path::new("."), &args(&["in.txt"])),
+            Ok(Source::File(PathBuf::from("in.txt")))
+        );
+        assert_eq!(
+            from_args(1, Path::new("."), &args(&["-"])),
+            Ok(Source::Stdin)
+        );
+        assert!(from_args(1, Path::new("."), &args(&["a.txt", "-"])).is_err());
+        assert!(from_args(1, Path::new("."), &args(&["--example"])).is_err());
+    }
+}
@@ -0,0 +1,9 @@
+pub mod geometry;
+pub mod input;
+pub mod parse;
+pub mod report;
+mod solution;
+
+pub use parse::ParseError;
+pub use report::{Format, Report};
+pub use solution::{main, run, Part, Solution};
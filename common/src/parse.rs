@@ -0,0 +1,205 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// An error found while parsing puzzle input.
+///
+/// Positions are tracked relative to the text that was being parsed when the error was
+/// created, and moved outwards with [`ParseError::within`] as the error is passed up to
+/// the parsers of the enclosing text. Lines and columns are zero based internally and
+/// reported one based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    text: String,
+    line: usize,
+    column: usize,
+}
+
+impl ParseError {
+    /// An error for `text`, found at the start of the text being parsed.
+    pub fn new(message: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            text: text.into(),
+            line: 0,
+            column: 0,
+        }
+    }
+
+    /// An error for `part`, which must be a slice of `source`.
+    pub fn at(source: &str, part: &str, message: impl Into<String>) -> Self {
+        Self::new(message, part).within(source, part)
+    }
+
+    /// Moves an error found while parsing `part` to its position within `source`.
+    pub fn within(mut self, source: &str, part: &str) -> Self {
+        let (line, column) = position(source, part);
+        if self.line == 0 {
+            self.column += column;
+        }
+        self.line += line;
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The one based line of the error.
+    pub fn line(&self) -> usize {
+        self.line + 1
+    }
+
+    /// The one based column of the error.
+    pub fn column(&self) -> usize {
+        self.column + 1
+    }
+
+    /// Renders the error with the offending line of `source` and a caret under the text, or
+    /// under as much of it as is on that line.
+    pub fn render(&self, path: &str, source: &str) -> String {
+        let line = source.lines().nth(self.line).unwrap_or("");
+        let number = self.line().to_string();
+        let gutter = " ".repeat(number.len());
+        let text = self.text.lines().next().unwrap_or("").chars().count();
+        let rest = line.chars().count().saturating_sub(self.column);
+        let caret = "^".repeat(text.min(rest).max(1));
+        format!(
+            "error: {}\n{gutter}--> {path}:{}:{}\n{gutter} |\n{number} | {line}\n{gutter} | {}{caret}",
+            self.message,
+            self.line(),
+            self.column(),
+            " ".repeat(self.column),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(line) = self.text.lines().next().filter(|line| !line.is_empty()) {
+            write!(f, " `{line}`")?;
+        }
+        write!(f, " at line {}, column {}", self.line(), self.column())
+    }
+}
+
+impl Error for ParseError {}
+
+/// The zero based line and column at which `part` starts within `source`.
+fn position(source: &str, part: &str) -> (usize, usize) {
+    let start = source.as_ptr() as usize;
+    let offset = (part.as_ptr() as usize).wrapping_sub(start);
+    if offset > source.len() || !source.is_char_boundary(offset) {
+        return (0, 0);
+    }
+    let prefix = &source[..offset];
+    let line = prefix.matches('\n').count();
+    let line_start = prefix.rfind('\n').map_or(0, |i| i + 1);
+    (line, prefix[line_start..].chars().count())
+}
+
+/// Splits `s` around the first `delimiter`, failing if it is missing.
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(format!("expected `{}`", delimiter.escape_debug()), s))
+}
+
+/// Parses `s` as a number.
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse::<T>()
+        .map_err(|_| ParseError::new("expected a number", s))
+}
+
+/// Parses each whitespace separated number in `s`.
+pub fn numbers<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    s.split_whitespace()
+        .map(|n| number(n).map_err(|e| e.within(s, n)))
+        .collect()
+}
+
+/// Parses each line of `input` with `f`, reporting errors at their position in `input`.
+pub fn lines<T>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|l| f(l).map_err(|e| e.within(input, l)))
+        .collect()
+}
+
+/// Parses each character of each line of `input` with `f`, requiring at least one row and
+/// every row to be the same width.
+pub fn grid<T>(
+    input: &str,
+    mut f: impl FnMut(char) -> Result<T, ParseError>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let width = input.lines().next().map(|l| l.chars().count());
+    let Some(width) = width else {
+        return Err(ParseError::new("expected at least one row", ""));
+    };
+    lines(input, |l| {
+        let row_width = l.chars().count();
+        if row_width != width {
+            return Err(ParseError::new(
+                format!("expected {width} columns, found {row_width}"),
+                l,
+            ));
+        }
+        l.char_indices()
+            .map(|(i, c)| f(c).map_err(|e| e.within(l, &l[i..])))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_within() {
+        let input = "12 34\n56 7x\n";
+        let line = input.lines().nth(1).unwrap();
+        let error = numbers::<usize>(line).unwrap_err().within(input, line);
+        assert_eq!(error.line(), 2);
+        assert_eq!(error.column(), 4);
+        assert_eq!(error.text(), "7x");
+    }
+
+    #[test]
+    fn test_grid() {
+        let input = "..#\n.x.";
+        let error = grid(input, |c| match c {
+            '.' | '#' => Ok(c),
+            _ => Err(ParseError::new("invalid cell", c)),
+        })
+        .unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 2));
+
+        let error = grid(".#\n.", Ok).unwrap_err();
+        assert_eq!(error.message(), "expected 2 columns, found 1");
+        assert_eq!(error.line(), 2);
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "R 6\nX 5\n";
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::at(input, &line[..1], "invalid direction");
+        assert_eq!(
+            error.render("input.txt", input),
+            "error: invalid direction\n --> input.txt:2:1\n  |\n2 | X 5\n  | ^"
+        );
+
+        let input = "LR\nAAA = (AAA, AAA)\n";
+        let error = split_once(input, "\n\n").unwrap_err();
+        assert_eq!(
+            error.render("input.txt", input),
+            "error: expected `\\n\\n`\n --> input.txt:1:1\n  |\n1 | LR\n  | ^^"
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use std::{str::FromStr, time::Instant};
+
+use serde::{Serialize, Serializer};
+
+use crate::{ParseError, Part, Solution};
+
+/// How answers are printed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("invalid format: {s}")),
+        }
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Part::One => serializer.serialize_u8(1),
+            Part::Two => serializer.serialize_u8(2),
+        }
+    }
+}
+
+/// The answer to one part of a day, with how long the input took to parse and the part took
+/// to solve.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub parse_ns: u128,
+    pub solve_ns: u128,
+}
+
+/// Parses `input` once, then solves each of `parts`, timing both.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<Report>, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_ns = start.elapsed().as_nanos();
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = S::solve(&parsed, part).to_string();
+            Report {
+                day: S::DAY,
+                part,
+                answer,
+                parse_ns,
+                solve_ns: start.elapsed().as_nanos(),
+            }
+        })
+        .collect())
+}
+
+pub fn to_json(reports: &[Report]) -> String {
+    serde_json::to_string(reports).expect("reports should serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let report = Report {
+            day: 9,
+            part: Part::Two,
+            answer: "1136".to_string(),
+            parse_ns: 90,
+            solve_ns: 70,
+        };
+        assert_eq!(
+            to_json(&[report]),
+            r#"[{"day":9,"part":2,"answer":"1136","parse_ns":90,"solve_ns":70}]"#
+        );
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    env,
+    fmt::{self, Debug, Display},
+    path::Path,
+    process,
+    str::FromStr,
+};
+
+use crate::{
+    input,
+    report::{self, Format},
+    ParseError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part: {s}")),
+        }
+    }
+}
+
+/// A day's puzzle, split into parsing and the two parts.
+///
+/// `Params` carries the values that differ between the parts of a puzzle, such as the
+/// expansion factor in day 11 or the number of steps in day 21. Days without any use `()`.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Params;
+    type Answer: Debug + Display + PartialEq;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// The parameters used by the actual puzzle for the given part.
+    fn params(part: Part) -> Self::Params;
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Self::Answer;
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Self::Answer;
+
+    /// Solves a part with the puzzle's own parameters.
+    fn solve(input: &Self::Input, part: Part) -> Self::Answer {
+        let params = Self::params(part);
+        match part {
+            Part::One => Self::part1(input, &params),
+            Part::Two => Self::part2(input, &params),
+        }
+    }
+}
+
+/// Parses the input read from `path` and prints the answer to both parts in `format`, exiting
+/// with a diagnostic if the input is malformed.
+pub fn run<S: Solution>(path: &str, input: &str, format: Format) {
+    let reports = match report::solve::<S>(input, &Part::ALL) {
+        Ok(reports) => reports,
+        Err(e) => {
+            eprintln!("{}", e.render(path, input));
+            process::exit(1);
+        }
+    };
+    match format {
+        Format::Text => {
+            for report in &reports {
+                println!("part {}: {}", report.part, report.answer);
+            }
+        }
+        Format::Json => println!("{}", report::to_json(&reports)),
+    }
+}
+
+/// Runs a day's own binary, reading the input picked on its command line and printing the
+/// answers in the format given by `--format`. Inputs are looked up in `dir`, the day's
+/// directory, unless `AOC_INPUTS` says otherwise.
+pub fn main<S: Solution>(dir: &str) {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = split_format(&args).and_then(|(format, args)| {
+        let source = input::from_args(S::DAY, Path::new(dir), &args)?;
+        Ok((format, source.to_string(), source.read()?))
+    });
+    match result {
+        Ok((format, path, input)) => run::<S>(&path, &input, format),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Takes `--format` out of `args`, leaving the rest.
+fn split_format(args: &[String]) -> Result<(Format, Vec<String>), String> {
+    let mut format = Format::default();
+    let mut rest = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" | "-f" => {
+                let value = args.next().ok_or("--format expects a value")?;
+                format = value.parse()?;
+            }
+            _ => rest.push(arg.clone()),
+        }
+    }
+    Ok((format, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<usize>;
+        type Params = usize;
+        type Answer = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input.split(',').map(crate::parse::number).collect()
+        }
+
+        fn params(part: Part) -> Self::Params {
+            match part {
+                Part::One => 1,
+                Part::Two => 10,
+            }
+        }
+
+        fn part1(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+            input.iter().sum::<usize>() * params
+        }
+
+        fn part2(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+            input.iter().product::<usize>() * params
+        }
+    }
+
+    #[test]
+    fn test_split_format() {
+        let args: Vec<String> = ["-", "--format", "json"].map(String::from).to_vec();
+        assert_eq!(
+            split_format(&args),
+            Ok((Format::Json, vec!["-".to_string()]))
+        );
+        assert!(split_format(&args[1..2]).is_err());
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = Sum::parse("1,2,3").unwrap();
+        assert_eq!(Sum::solve(&input, Part::One), 6);
+        assert_eq!(Sum::solve(&input, Part::Two), 60);
+    }
+}
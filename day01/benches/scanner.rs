@@ -0,0 +1,50 @@
+use std::fs;
+
+use common::{Part, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day01::{Calibration, Day01};
+
+/// How part 2 used to find digits, rewriting each word so its digit appears in the line
+/// while keeping the letters other words may overlap with.
+fn replace_chain(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .map(|l| {
+            l.replace("one", "one1one")
+                .replace("two", "two2two")
+                .replace("three", "three3three")
+                .replace("four", "four4four")
+                .replace("five", "five5five")
+                .replace("six", "six6six")
+                .replace("seven", "seven7seven")
+                .replace("eight", "eight8eight")
+                .replace("nine", "nine9nine")
+                .chars()
+                .filter_map(|c| c.to_digit(10))
+                .collect::<Vec<_>>()
+        })
+        .map(|l| 10 * l.first().expect("number expected") + l.last().expect("number expected"))
+        .sum()
+}
+
+fn part2(c: &mut Criterion) {
+    let input = fs::read_to_string("input.txt").expect("failed to read input file");
+    let lines = Day01::parse(&input).expect("failed to parse input");
+    let scanner = Day01::params(Part::Two);
+    assert_eq!(
+        Calibration::Sum(replace_chain(&lines)),
+        day01::solve(&lines, &scanner)
+    );
+
+    let mut group = c.benchmark_group("day01 part 2");
+    group.bench_function("replace chain", |b| {
+        b.iter(|| replace_chain(black_box(&lines)))
+    });
+    group.bench_function("scanner", |b| {
+        b.iter(|| day01::solve(black_box(&lines), &scanner))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, part2);
+criterion_main!(benches);
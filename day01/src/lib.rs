@@ -0,0 +1,114 @@
+use std::fmt;
+
+use common::{parse, ParseError, Part, Solution};
+
+mod scanner;
+
+pub use scanner::{Match, Scanner, DIGITS, WORDS};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Params = Scanner;
+    type Answer = Calibration;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input, |l| {
+            match l.char_indices().find(|(_, c)| !c.is_ascii_alphanumeric()) {
+                Some((i, c)) => Err(ParseError::at(
+                    l,
+                    &l[i..i + c.len_utf8()],
+                    "expected a letter or digit",
+                )),
+                None => Ok(l.to_string()),
+            }
+        })
+    }
+
+    fn params(part: Part) -> Self::Params {
+        match part {
+            Part::One => Scanner::new(DIGITS),
+            Part::Two => Scanner::new(DIGITS.into_iter().chain(WORDS)),
+        }
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+        solve(input, params)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+        solve(input, params)
+    }
+}
+
+/// The first and last value found on a line, read as a two digit number.
+pub fn calibration_value(line: &str, scanner: &Scanner) -> Option<u32> {
+    let first = scanner.first(line)?;
+    let last = scanner.last(line)?;
+    Some(10 * first.value + last.value)
+}
+
+/// The sum of every line's calibration value, or the first line that has none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Calibration {
+    Sum(u32),
+    /// The one based number of a line the scanner finds nothing on.
+    Missing(usize),
+}
+
+impl fmt::Display for Calibration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Calibration::Sum(sum) => write!(f, "{sum}"),
+            Calibration::Missing(line) => write!(f, "no value on line {line}"),
+        }
+    }
+}
+
+pub fn solve(lines: &[String], scanner: &Scanner) -> Calibration {
+    let mut sum = 0;
+    for (i, line) in lines.iter().enumerate() {
+        match calibration_value(line, scanner) {
+            Some(value) => sum += value,
+            None => return Calibration::Missing(i + 1),
+        }
+    }
+    Calibration::Sum(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read input file");
+        let lines = Day01::parse(&input).unwrap();
+        assert_eq!(
+            solve(&lines, &Day01::params(Part::One)),
+            Calibration::Sum(142)
+        );
+    }
+
+    #[test]
+    fn test_missing() {
+        let input = fs::read_to_string("test_input2.txt").expect("failed to read input file");
+        let lines = Day01::parse(&input).unwrap();
+        let missing = solve(&lines, &Day01::params(Part::One));
+        assert_eq!(missing.to_string(), "no value on line 2");
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input2.txt").expect("failed to read input file");
+        let lines = Day01::parse(&input).unwrap();
+        assert_eq!(
+            solve(&lines, &Day01::params(Part::Two)),
+            Calibration::Sum(281)
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::VecDeque;
+
+/// The digits `0` to `9`.
+pub const DIGITS: [(&str, u32); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+/// The digits `one` to `nine` spelled out in English.
+pub const WORDS: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// A token found in some text, spanning the bytes `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+/// Finds the first and last of a set of tokens in text, where matches may overlap, so that
+/// `eightwo` starts with `eight` and ends with `two`.
+///
+/// Each direction is an Aho-Corasick automaton, the backward one built from the reversed
+/// tokens, so either end of a line is found in a single pass from that end.
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    forward: Automaton,
+    backward: Automaton,
+    longest: usize,
+}
+
+impl Scanner {
+    /// A scanner for `tokens`, each paired with the value it stands for. Later tokens replace
+    /// earlier ones with the same text.
+    pub fn new<'a>(tokens: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        let tokens: Vec<_> = tokens.into_iter().collect();
+        assert!(
+            tokens.iter().all(|(token, _)| !token.is_empty()),
+            "tokens can't be empty"
+        );
+        let reversed = tokens
+            .iter()
+            .map(|&(token, value)| (token.bytes().rev().collect(), value));
+        Self {
+            forward: Automaton::new(tokens.iter().map(|&(t, v)| (t.as_bytes().to_vec(), v))),
+            backward: Automaton::new(reversed),
+            longest: tokens.iter().map(|(t, _)| t.len()).max().unwrap_or(0),
+        }
+    }
+
+    /// The match that starts first, preferring the longest where several start together.
+    pub fn first(&self, text: &str) -> Option<Match> {
+        let (start, len, value) = self.forward.leftmost(text.bytes(), self.longest)?;
+        Some(Match {
+            start,
+            end: start + len,
+            value,
+        })
+    }
+
+    /// The match that ends last, scanning backwards from the end of `text`.
+    pub fn last(&self, text: &str) -> Option<Match> {
+        let (start, len, value) = self.backward.leftmost(text.bytes().rev(), self.longest)?;
+        let end = text.len() - start;
+        Some(Match {
+            start: end - len,
+            end,
+            value,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Automaton {
+    /// The next state for every state and byte, with failure links already followed.
+    transitions: Vec<[u32; 256]>,
+    /// The length and value of the longest token ending at each state.
+    outputs: Vec<Option<(usize, u32)>>,
+}
+
+impl Automaton {
+    fn new(tokens: impl IntoIterator<Item = (Vec<u8>, u32)>) -> Self {
+        // build the trie, with 0 marking a missing edge since nothing leads back to the root
+        let mut transitions = vec![[0; 256]];
+        let mut outputs = vec![None];
+        for (token, value) in tokens {
+            let mut state = 0;
+            for &b in &token {
+                if transitions[state][b as usize] == 0 {
+                    transitions.push([0; 256]);
+                    outputs.push(None);
+                    transitions[state][b as usize] = (transitions.len() - 1) as u32;
+                }
+                state = transitions[state][b as usize] as usize;
+            }
+            outputs[state] = Some((token.len(), value));
+        }
+
+        // fill in the missing edges breadth first, from each state's failure link
+        let mut fail = vec![0; transitions.len()];
+        let mut queue: VecDeque<usize> = transitions[0]
+            .iter()
+            .filter(|&&next| next != 0)
+            .map(|&next| next as usize)
+            .collect();
+        while let Some(state) = queue.pop_front() {
+            if outputs[state].is_none() {
+                outputs[state] = outputs[fail[state]];
+            }
+            let fallbacks = transitions[fail[state]];
+            for (next, fallback) in transitions[state].iter_mut().zip(fallbacks) {
+                if *next == 0 {
+                    *next = fallback;
+                } else {
+                    fail[*next as usize] = fallback as usize;
+                    queue.push_back(*next as usize);
+                }
+            }
+        }
+        Self {
+            transitions,
+            outputs,
+        }
+    }
+
+    /// The start, length and value of the leftmost match in `bytes`, preferring the longest
+    /// where several start together. Scanning stops once no later match could start at or
+    /// before the best so far.
+    fn leftmost(
+        &self,
+        bytes: impl Iterator<Item = u8>,
+        longest: usize,
+    ) -> Option<(usize, usize, u32)> {
+        let mut state = 0;
+        let mut best: Option<(usize, usize, u32)> = None;
+        for (i, b) in bytes.enumerate() {
+            if best.is_some_and(|(start, _, _)| i >= start + longest) {
+                break;
+            }
+            state = self.transitions[state][b as usize] as usize;
+            if let Some((len, value)) = self.outputs[state] {
+                let start = i + 1 - len;
+                let better = |(best_start, best_len, _): (usize, usize, u32)| {
+                    start < best_start || (start == best_start && len > best_len)
+                };
+                if best.is_none_or(better) {
+                    best = Some((start, len, value));
+                }
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn english() -> Scanner {
+        Scanner::new(DIGITS.into_iter().chain(WORDS))
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let scanner = english();
+        let first = scanner.first("eightwothree").unwrap();
+        assert_eq!((first.start, first.end, first.value), (0, 5, 8));
+        let last = scanner.last("xtwone3four").unwrap();
+        assert_eq!((last.start, last.end, last.value), (7, 11, 4));
+        assert_eq!(scanner.last("eightwo").unwrap().value, 2);
+        assert_eq!(scanner.first("zoneight234").unwrap().value, 1);
+        assert_eq!(scanner.first("xyz"), None);
+    }
+
+    #[test]
+    fn test_leftmost_longest() {
+        // `bc` ends first, but `abcd` starts first
+        let scanner = Scanner::new([("abcd", 1), ("bc", 2)]);
+        assert_eq!(scanner.first("abcd").unwrap().value, 1);
+        assert_eq!(scanner.last("abcd").unwrap().value, 1);
+        assert_eq!(scanner.last("abcdbc").unwrap().value, 2);
+
+        // `ab` and `abc` start together, so the longer wins
+        let scanner = Scanner::new([("ab", 1), ("abc", 2)]);
+        assert_eq!(scanner.first("abc").unwrap().value, 2);
+        assert_eq!(scanner.first("abx").unwrap().value, 1);
+        assert_eq!(
+            Scanner::new([("bc", 1), ("abc", 2)])
+                .last("abc")
+                .unwrap()
+                .value,
+            2
+        );
+    }
+
+    #[test]
+    fn test_vocabulary() {
+        let scanner = Scanner::new(DIGITS.into_iter().chain(WORDS).chain([("zero", 0)]));
+        assert_eq!(scanner.first("zerone").unwrap().value, 0);
+        assert_eq!(scanner.last("zerone").unwrap().value, 1);
+
+        let german = Scanner::new([("eins", 1), ("zwei", 2), ("drei", 3), ("fünf", 5)]);
+        let last = german.last("zweifünfx").unwrap();
+        assert_eq!((last.start, last.end, last.value), (4, 9, 5));
+        assert_eq!(german.first("xdreins").unwrap().value, 3);
+    }
+}
@@ -0,0 +1,168 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{parse, ParseError, Part, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Params = ();
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input, Game::from_str)
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part1(input, &puzzle_bag())
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input, &puzzle_bag())
+    }
+}
+
+/// How many cubes of each colour are in a bag.
+pub type Bag = HashMap<String, usize>;
+
+/// The bag the elf asks about in part 1.
+pub fn puzzle_bag() -> Bag {
+    [("red", 12), ("green", 13), ("blue", 14)]
+        .into_iter()
+        .map(|(colour, count)| (colour.to_string(), count))
+        .collect()
+}
+
+/// The product of the counts in `bag`.
+pub fn power(bag: &Bag) -> usize {
+    bag.values().product()
+}
+
+/// A handful of cubes shown from the bag, counted by colour.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Draw {
+    pub cubes: HashMap<String, usize>,
+}
+
+impl Draw {
+    /// Whether this many cubes could have come from `bag`.
+    pub fn fits(&self, bag: &Bag) -> bool {
+        self.cubes
+            .iter()
+            .all(|(colour, &count)| count <= bag.get(colour).copied().unwrap_or(0))
+    }
+}
+
+impl FromStr for Draw {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cubes = HashMap::new();
+        for cube in s.split(", ") {
+            let (count, colour) = parse::split_once(cube, " ").map_err(|e| e.within(s, cube))?;
+            let count: usize = parse::number(count).map_err(|e| e.within(s, count))?;
+            if colour.is_empty() || !colour.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(s, colour, "expected a colour"));
+            }
+            *cubes.entry(colour.to_string()).or_default() += count;
+        }
+        Ok(Self { cubes })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: usize,
+    pub draws: Vec<Draw>,
+}
+
+impl Game {
+    /// Whether every draw in the game could have come from `bag`.
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.draws.iter().all(|draw| draw.fits(bag))
+    }
+
+    /// The fewest cubes of each colour that make the game possible, counting every colour in
+    /// `colours` even if the game never shows it.
+    pub fn minimum_bag(&self, colours: &Bag) -> Bag {
+        let mut bag: Bag = colours.keys().map(|colour| (colour.clone(), 0)).collect();
+        for (colour, &count) in self.draws.iter().flat_map(|draw| &draw.cubes) {
+            let most = bag.entry(colour.clone()).or_default();
+            *most = (*most).max(count);
+        }
+        bag
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(l: &str) -> Result<Self, Self::Err> {
+        let (id_element, draws_str) = parse::split_once(l, ": ")?;
+        let (_, id_str) = parse::split_once(id_element, " ")?;
+        let id = parse::number(id_str).map_err(|e| e.within(l, id_str))?;
+        let draws = draws_str
+            .split("; ")
+            .map(|draw| Draw::from_str(draw).map_err(|e| e.within(l, draw)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { id, draws })
+    }
+}
+
+pub fn part1(games: &[Game], bag: &Bag) -> usize {
+    games
+        .iter()
+        .filter(|game| game.is_possible(bag))
+        .map(|game| game.id)
+        .sum()
+}
+
+/// The total power of each game's minimum bag over the colours in `bag`.
+pub fn part2(games: &[Game], bag: &Bag) -> usize {
+    games.iter().map(|game| power(&game.minimum_bag(bag))).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn games() -> Vec<Game> {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        Day02::parse(&input).unwrap()
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&games(), &puzzle_bag()), 8);
+    }
+
+    #[test]
+    fn test_part2() {
+        let games = games();
+        assert_eq!(power(&games[0].minimum_bag(&puzzle_bag())), 48);
+        assert_eq!(part2(&games, &puzzle_bag()), 2286);
+    }
+
+    #[test]
+    fn test_missing_colour() {
+        let games = Day02::parse("Game 1: 3 red\nGame 2: 4 blue, 1 green").unwrap();
+        assert_eq!(games[0].minimum_bag(&puzzle_bag())["green"], 0);
+        assert_eq!(part2(&games, &puzzle_bag()), 0);
+    }
+
+    #[test]
+    fn test_custom_bag() {
+        let game: Game = "Game 7: 2 purple, 1 red; 3 purple".parse().unwrap();
+        assert_eq!(game.minimum_bag(&puzzle_bag())["purple"], 3);
+        let mut bag = puzzle_bag();
+        assert!(!game.is_possible(&bag));
+        bag.insert("purple".to_string(), 3);
+        assert!(game.is_possible(&bag));
+        assert!("Game 1: 3 bl3e".parse::<Game>().is_err());
+    }
+}
@@ -0,0 +1,392 @@
+use std::{collections::BTreeMap, fs, ops::Index, str::FromStr};
+
+use common::{ParseError, Part, Solution};
+use grid::{Grid, Position};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Map;
+    type Params = ();
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input, &GearRule::default())
+    }
+}
+
+/// A run of digits in the schematic, read left to right.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub value: usize,
+    pub row: usize,
+    pub column: usize,
+    pub size: usize,
+}
+
+impl Number {
+    fn new(value: usize, row: usize, column: usize) -> Self {
+        Number {
+            value,
+            row,
+            column,
+            size: 1,
+        }
+    }
+
+    fn add(&mut self, value: usize) {
+        self.value = self.value * 10 + value;
+        self.size += 1;
+    }
+}
+
+/// Anything other than a digit or `.`.
+pub fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+#[derive(Debug)]
+pub struct Map {
+    grid: Grid<char>,
+}
+
+impl Map {
+    pub fn from_file(file_name: &str) -> Self {
+        let content = fs::read_to_string(file_name).expect("File not found");
+        content.parse().expect("invalid map")
+    }
+
+    /// Every number in the schematic, row by row.
+    pub fn numbers(&self) -> impl Iterator<Item = Number> + '_ {
+        self.grid
+            .rows()
+            .enumerate()
+            .flat_map(|(row, values)| row_numbers(row, values))
+    }
+
+    /// The positions touching `number`, including diagonally, without repeats.
+    fn neighbours(&self, number: &Number) -> Vec<Position> {
+        let digits = number.column..number.column + number.size;
+        let mut neighbours: Vec<_> = digits
+            .clone()
+            .flat_map(|x| self.grid.neighbours8(Position::new(x, number.row)))
+            .filter(|p| p.y != number.row || !digits.contains(&p.x))
+            .collect();
+        neighbours.sort();
+        neighbours.dedup();
+        neighbours
+    }
+
+    fn is_adjacent_to_symbol(&self, number: &Number) -> bool {
+        self.neighbours(number)
+            .into_iter()
+            .any(|p| is_symbol(self.grid[p]))
+    }
+
+    /// Indexes every symbol by its position, along with the numbers touching it.
+    pub fn symbols(&self) -> SymbolIndex {
+        let mut symbols: BTreeMap<_, _> = self
+            .grid
+            .iter()
+            .filter(|(_, &c)| is_symbol(c))
+            .map(|(p, &c)| (p, (c, Vec::new())))
+            .collect();
+        for number in self.numbers() {
+            for p in self.neighbours(&number) {
+                if let Some((_, numbers)) = symbols.get_mut(&p) {
+                    numbers.push(number.clone());
+                }
+            }
+        }
+        SymbolIndex { symbols }
+    }
+}
+
+/// The numbers in one row of the schematic.
+fn row_numbers(row: usize, values: &[char]) -> impl Iterator<Item = Number> + '_ {
+    let mut column = 0;
+    std::iter::from_fn(move || {
+        while column < values.len() && !values[column].is_ascii_digit() {
+            column += 1;
+        }
+        let mut number: Option<Number> = None;
+        while let Some(digit) = values.get(column).and_then(|c| c.to_digit(10)) {
+            match &mut number {
+                Some(n) => n.add(digit as usize),
+                None => number = Some(Number::new(digit as usize, row, column)),
+            }
+            column += 1;
+        }
+        number
+    })
+}
+
+/// The symbols in a schematic, each with the numbers adjacent to it.
+#[derive(Debug)]
+pub struct SymbolIndex {
+    symbols: BTreeMap<Position, (char, Vec<Number>)>,
+}
+
+impl SymbolIndex {
+    /// The symbol at `position`, if there is one.
+    pub fn symbol(&self, position: Position) -> Option<char> {
+        self.symbols.get(&position).map(|(c, _)| *c)
+    }
+
+    /// The numbers touching the symbol at `position`, which is empty if there is no symbol
+    /// there.
+    pub fn adjacent(&self, position: Position) -> &[Number] {
+        self.symbols
+            .get(&position)
+            .map_or(&[], |(_, numbers)| numbers.as_slice())
+    }
+
+    /// Every symbol in reading order, with its position and adjacent numbers.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, char, &[Number])> {
+        self.symbols
+            .iter()
+            .map(|(&p, (c, numbers))| (p, *c, numbers.as_slice()))
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse_with(content, |c| {
+            if c.is_ascii_graphic() {
+                Ok(c)
+            } else {
+                Err(ParseError::new("invalid character", c))
+            }
+        })?;
+        Ok(Map { grid })
+    }
+}
+
+impl Index<usize> for Map {
+    type Output = [char];
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.grid.row(index)
+    }
+}
+
+pub fn part1(map: &Map) -> usize {
+    map.numbers()
+        .filter(|n| map.is_adjacent_to_symbol(n))
+        .map(|n| n.value)
+        .sum()
+}
+
+/// How the numbers around a gear combine into its ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {
+    Product,
+    Sum,
+}
+
+impl Combine {
+    pub fn apply(self, values: impl Iterator<Item = usize>) -> usize {
+        match self {
+            Combine::Product => values.product(),
+            Combine::Sum => values.sum(),
+        }
+    }
+}
+
+/// What makes a symbol a gear: being one of `symbols` and touching exactly `neighbours`
+/// numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GearRule {
+    pub symbols: Vec<char>,
+    pub neighbours: usize,
+    pub combine: Combine,
+}
+
+impl Default for GearRule {
+    /// A `*` touching exactly two numbers, whose ratio is their product.
+    fn default() -> Self {
+        GearRule {
+            symbols: vec!['*'],
+            neighbours: 2,
+            combine: Combine::Product,
+        }
+    }
+}
+
+impl GearRule {
+    pub fn is_gear(&self, symbol: char, numbers: &[Number]) -> bool {
+        self.symbols.contains(&symbol) && numbers.len() == self.neighbours
+    }
+
+    /// The ratio of every gear in the schematic, in reading order.
+    pub fn ratios<'a>(&'a self, index: &'a SymbolIndex) -> impl Iterator<Item = usize> + 'a {
+        index
+            .iter()
+            .filter(|&(_, c, numbers)| self.is_gear(c, numbers))
+            .map(|(_, _, numbers)| self.combine.apply(numbers.iter().map(|n| n.value)))
+    }
+}
+
+/// The part numbers touching each kind of symbol. A number touching several symbols of one
+/// kind is listed once under it.
+pub fn parts_by_symbol(map: &Map) -> BTreeMap<char, Vec<Number>> {
+    let mut parts: BTreeMap<char, Vec<Number>> = BTreeMap::new();
+    for (_, c, numbers) in map.symbols().iter() {
+        let group = parts.entry(c).or_default();
+        for number in numbers {
+            if !group.contains(number) {
+                group.push(number.clone());
+            }
+        }
+    }
+    for group in parts.values_mut() {
+        group.sort_by_key(|n| (n.row, n.column));
+    }
+    parts
+}
+
+/// One line per kind of symbol, giving the part numbers touching it and their total.
+pub fn symbol_report(map: &Map) -> String {
+    parts_by_symbol(map)
+        .iter()
+        .map(|(c, numbers)| {
+            let values: Vec<_> = numbers.iter().map(|n| n.value.to_string()).collect();
+            let total: usize = numbers.iter().map(|n| n.value).sum();
+            format!("{c}: {} (total {total})\n", values.join(", "))
+        })
+        .collect()
+}
+
+pub fn part2(map: &Map, rule: &GearRule) -> usize {
+    rule.ratios(&map.symbols()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_map() {
+        let map = Map::from_file("test_input.txt");
+        assert_eq!(map[0][0], '4');
+        assert_eq!(map[0][1], '6');
+        assert_eq!(map[0][2], '7');
+        assert_eq!(map[9][7], '8');
+        assert_eq!(map[9][8], '.');
+        assert_eq!(map[9][9], '.');
+        assert_eq!(map.grid.height(), 10);
+        assert_eq!(map.grid.width(), 10);
+    }
+
+    #[test]
+    fn test_is_adjacent() {
+        let map = Map::from_file("test_input.txt");
+        let mut number1 = Number::new(6, 4, 0);
+        number1.add(1);
+        number1.add(7);
+        assert_eq!(number1.size, 3);
+        assert_eq!(number1.value, 617);
+        assert!(map.is_adjacent_to_symbol(&number1));
+
+        let mut number2 = Number::new(5, 9, 5);
+        number2.add(9);
+        number2.add(8);
+        assert_eq!(number2.size, 3);
+        assert_eq!(number2.value, 598);
+        assert!(map.is_adjacent_to_symbol(&number2));
+    }
+
+    #[test]
+    fn test_adjacent_right_edge() {
+        let map = Map::from_file("test_input.txt");
+        let mut number = Number::new(7, 7, 6);
+        number.add(5);
+        number.add(5);
+        number.add(1);
+        assert_eq!(number.size, 4);
+        assert_eq!(number.value, 7551);
+        assert!(map.is_adjacent_to_symbol(&number));
+    }
+
+    #[test]
+    fn test_part1() {
+        let map = Map::from_file("test_input.txt");
+        assert_eq!(part1(&map), 4361);
+    }
+
+    #[test]
+    fn test_part2() {
+        let map = Map::from_file("test_input.txt");
+        assert_eq!(part2(&map, &GearRule::default()), 467835);
+    }
+
+    #[test]
+    fn test_numbers() {
+        let map = Map::from_file("test_input.txt");
+        let values: Vec<_> = map.numbers().map(|n| n.value).collect();
+        assert_eq!(values, [467, 114, 35, 633, 617, 58, 592, 755, 664, 598]);
+        let last = map.numbers().last().unwrap();
+        assert_eq!((last.row, last.column, last.size), (9, 5, 3));
+    }
+
+    #[test]
+    fn test_symbols() {
+        let map = Map::from_file("test_input.txt");
+        let symbols = map.symbols();
+        let star = Position::new(3, 1);
+        assert_eq!(symbols.symbol(star), Some('*'));
+        let values: Vec<_> = symbols.adjacent(star).iter().map(|n| n.value).collect();
+        assert_eq!(values, [467, 35]);
+        assert!(symbols.adjacent(Position::new(0, 0)).is_empty());
+        assert_eq!(symbols.iter().filter(|&(_, c, _)| c == '$').count(), 1);
+    }
+
+    #[test]
+    fn test_gear_rules() {
+        let map = Map::from_file("test_input.txt");
+        let sum = GearRule {
+            combine: Combine::Sum,
+            ..GearRule::default()
+        };
+        assert_eq!(part2(&map, &sum), 467 + 35 + 755 + 598);
+
+        let lonely = GearRule {
+            symbols: vec!['*', '#', '+', '$'],
+            neighbours: 1,
+            combine: Combine::Sum,
+        };
+        assert_eq!(part2(&map, &lonely), 633 + 617 + 592 + 664);
+
+        // the first gear is further right, but a row higher
+        let map: Map = "...2*3\n......\n4*5...".parse().unwrap();
+        let symbols = map.symbols();
+        let ratios: Vec<_> = GearRule::default().ratios(&symbols).collect();
+        assert_eq!(ratios, [6, 20]);
+    }
+
+    #[test]
+    fn test_symbol_report() {
+        let map = Map::from_file("test_input.txt");
+        assert_eq!(
+            symbol_report(&map),
+            "#: 633 (total 633)\n\
+             $: 664 (total 664)\n\
+             *: 467, 35, 617, 755, 598 (total 2472)\n\
+             +: 592 (total 592)\n"
+        );
+    }
+}
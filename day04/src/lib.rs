@@ -0,0 +1,258 @@
+use std::{collections::HashSet, str::FromStr};
+
+use common::{parse, ParseError, Part, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Params = Scoring;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input, Card::from_str)
+    }
+
+    fn params(part: Part) -> Self::Params {
+        match part {
+            Part::One => Scoring::Doubling,
+            Part::Two => Scoring::Copies,
+        }
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+        params.score(input)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+        params.score(input)
+    }
+}
+
+/// The rules a pile of scratchcards is scored by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scoring {
+    /// One point for the first match, doubled for each match after it.
+    Doubling,
+    /// One point for each match.
+    Linear,
+    /// 1, 2, 3, 5, 8 and so on points for 1, 2, 3, 4, 5 matches.
+    Fibonacci,
+    /// Each match wins a copy of one of the following cards, scoring the number of cards
+    /// held at the end.
+    Copies,
+    /// As `Copies`, but no more than this many of any one card can be held, such as the size
+    /// of the deck.
+    CappedCopies(usize),
+}
+
+impl Scoring {
+    /// The points for a card with `matches` matches, for rules that score cards by points.
+    pub fn points(self, matches: usize) -> Option<usize> {
+        match self {
+            Scoring::Doubling => Some(match matches {
+                0 => 0,
+                n => 2usize.pow((n - 1) as u32),
+            }),
+            Scoring::Linear => Some(matches),
+            Scoring::Fibonacci => {
+                let (mut a, mut b) = (0, 1);
+                for _ in 0..matches {
+                    (a, b) = (b, a + b);
+                }
+                Some(if matches == 0 { 0 } else { b })
+            }
+            Scoring::Copies | Scoring::CappedCopies(_) => None,
+        }
+    }
+
+    pub fn score(self, cards: &[Card]) -> usize {
+        match self {
+            Scoring::Copies => Cascade::new(cards).total(),
+            Scoring::CappedCopies(cap) => Cascade::capped(cards, cap).total(),
+            _ => cards
+                .iter()
+                .map(|card| self.points(card.matches).expect("scored by points"))
+                .sum(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Card {
+    pub id: usize,
+    pub winning: HashSet<usize>,
+    pub numbers: HashSet<usize>,
+    /// How many of `numbers` are winning numbers.
+    pub matches: usize,
+}
+
+impl Card {
+    pub fn new(id: usize, winning: HashSet<usize>, numbers: HashSet<usize>) -> Self {
+        let matches = winning.intersection(&numbers).count();
+        Self {
+            id,
+            winning,
+            numbers,
+            matches,
+        }
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(l: &str) -> Result<Self, Self::Err> {
+        let (name_str, card) = parse::split_once(l, ": ")?;
+        let id_str = name_str.rsplit(' ').next().unwrap_or(name_str);
+        let id = parse::number(id_str).map_err(|e| e.within(l, id_str))?;
+        let (winning_str, numbers_str) =
+            parse::split_once(card, " | ").map_err(|e| e.within(l, card))?;
+        Ok(Self::new(
+            id,
+            extract_numbers(winning_str).map_err(|e| e.within(l, winning_str))?,
+            extract_numbers(numbers_str).map_err(|e| e.within(l, numbers_str))?,
+        ))
+    }
+}
+
+fn extract_numbers(numbers: &str) -> Result<HashSet<usize>, ParseError> {
+    Ok(parse::numbers(numbers)?.into_iter().collect())
+}
+
+/// How many copies of each card end up being scratched, where a card with `n` matches wins a
+/// copy of each of the next `n` cards for every copy of it.
+#[derive(Debug)]
+pub struct Cascade {
+    ids: Vec<usize>,
+    matches: Vec<usize>,
+    copies: Vec<usize>,
+}
+
+impl Cascade {
+    pub fn new(cards: &[Card]) -> Self {
+        Self::capped(cards, usize::MAX)
+    }
+
+    /// As `new`, but holding no more than `cap` copies of any card.
+    ///
+    /// Works through `cards` in order, keeping the copies won by earlier cards as a
+    /// difference array so each card is only visited once.
+    pub fn capped(cards: &[Card], cap: usize) -> Self {
+        let mut won = vec![0isize; cards.len() + 1];
+        let mut running = 0;
+        let mut copies = Vec::with_capacity(cards.len());
+        for (i, card) in cards.iter().enumerate() {
+            running += won[i];
+            let count = (1 + running as usize).min(cap);
+            copies.push(count);
+            let end = (i + 1 + card.matches).min(cards.len());
+            if i + 1 < end {
+                won[i + 1] += count as isize;
+                won[end] -= count as isize;
+            }
+        }
+        Self {
+            ids: cards.iter().map(|card| card.id).collect(),
+            matches: cards.iter().map(|card| card.matches).collect(),
+            copies,
+        }
+    }
+
+    fn index(&self, id: usize) -> Option<usize> {
+        self.ids.iter().position(|&other| other == id)
+    }
+
+    /// The copies of card `id`, counting the original.
+    pub fn copies(&self, id: usize) -> Option<usize> {
+        self.index(id).map(|i| self.copies[i])
+    }
+
+    /// The cards that won copies of card `id`, each with how many copies it won.
+    pub fn contributors(&self, id: usize) -> Vec<(usize, usize)> {
+        let Some(i) = self.index(id) else {
+            return Vec::new();
+        };
+        (0..i)
+            .filter(|&j| j + self.matches[j] >= i)
+            .map(|j| (self.ids[j], self.copies[j]))
+            .collect()
+    }
+
+    pub fn total(&self) -> usize {
+        self.copies.iter().sum()
+    }
+}
+
+pub fn part1(cards: &[Card]) -> usize {
+    Scoring::Doubling.score(cards)
+}
+
+pub fn part2(cards: &[Card]) -> usize {
+    Scoring::Copies.score(cards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_extract_numbers() {
+        let line = "83 86  6 31 17  9 48 53";
+        let numbers = extract_numbers(line).unwrap();
+
+        assert_eq!(numbers, HashSet::from([83, 86, 6, 31, 17, 9, 48, 53]));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to load file");
+        let result = part1(&Day04::parse(&input).unwrap());
+        assert_eq!(result, 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to load file");
+        let result = part2(&Day04::parse(&input).unwrap());
+        assert_eq!(result, 30);
+    }
+
+    #[test]
+    fn test_cascade() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to load file");
+        let cascade = Cascade::new(&Day04::parse(&input).unwrap());
+        let copies: Vec<_> = (1..=6).map(|id| cascade.copies(id).unwrap()).collect();
+        assert_eq!(copies, [1, 2, 4, 8, 14, 1]);
+        assert_eq!(cascade.copies(7), None);
+        assert_eq!(cascade.contributors(5), [(1, 1), (3, 4), (4, 8)]);
+        assert_eq!(cascade.contributors(1), []);
+        assert_eq!(cascade.contributors(6), []);
+    }
+
+    #[test]
+    fn test_scoring() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to load file");
+        let cards = Day04::parse(&input).unwrap();
+        assert_eq!(Scoring::Doubling.score(&cards), 8 + 2 + 2 + 1);
+        assert_eq!(Scoring::Linear.score(&cards), 4 + 2 + 2 + 1);
+        assert_eq!(Scoring::Fibonacci.score(&cards), 5 + 2 + 2 + 1);
+        assert_eq!(Scoring::Copies.score(&cards), 30);
+        assert_eq!(
+            Scoring::CappedCopies(cards.len()).score(&cards),
+            1 + 2 + 4 + 6 + 6 + 1
+        );
+        assert_eq!(Scoring::CappedCopies(1).score(&cards), cards.len());
+    }
+
+    #[test]
+    fn test_points() {
+        let fibonacci: Vec<_> = (0..7).map(|n| Scoring::Fibonacci.points(n)).collect();
+        assert_eq!(fibonacci, [0, 1, 2, 3, 5, 8, 13].map(Some));
+        assert_eq!(Scoring::Doubling.points(5), Some(16));
+        assert_eq!(Scoring::Copies.points(5), None);
+    }
+}
@@ -0,0 +1,311 @@
+use std::ops::Range;
+
+use rayon::prelude::*;
+
+use common::{parse, ParseError, Part, Solution};
+
+mod piecewise;
+
+pub use piecewise::{Piece, Piecewise};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Params = ();
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Map {
+    source: usize,
+    destination: usize,
+    length: usize,
+}
+
+impl Map {
+    fn new(destination: usize, source: usize, length: usize) -> Self {
+        Self {
+            source,
+            destination,
+            length,
+        }
+    }
+
+    fn partner(&self, source: usize) -> Option<usize> {
+        if source >= self.source && source < self.source + self.length {
+            Some(self.destination + (source - self.source))
+        } else {
+            None
+        }
+    }
+
+    /// The part of `range` this map covers, if any.
+    fn intersect(&self, range: &Range<usize>) -> Option<Range<usize>> {
+        let start = range.start.max(self.source);
+        let end = range.end.min(self.source + self.length);
+        (start < end).then_some(start..end)
+    }
+
+    /// Splits `range` into the part this map moves, already moved to its destination, and
+    /// the parts either side of it that the map leaves alone.
+    fn split(&self, range: Range<usize>) -> (Option<Range<usize>>, Vec<Range<usize>>) {
+        let Some(inside) = self.intersect(&range) else {
+            return (None, vec![range]);
+        };
+        let mut rest = Vec::new();
+        if range.start < inside.start {
+            rest.push(range.start..inside.start);
+        }
+        if inside.end < range.end {
+            rest.push(inside.end..range.end);
+        }
+        let offset = |x: usize| self.destination + (x - self.source);
+        (Some(offset(inside.start)..offset(inside.end)), rest)
+    }
+}
+
+/// Sends every value in `ranges` through one stage of maps, where values no map covers keep
+/// their number.
+fn map_ranges(maps: &[Map], ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    let mut mapped = Vec::new();
+    let mut unmapped = ranges;
+    for map in maps {
+        let mut rest = Vec::new();
+        for range in unmapped {
+            let (moved, left) = map.split(range);
+            mapped.extend(moved);
+            rest.extend(left);
+        }
+        unmapped = rest;
+    }
+    mapped.extend(unmapped);
+    mapped
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<usize>,
+    map_collection: Vec<Vec<Map>>,
+}
+
+impl Almanac {
+    /// The seeds as part two reads them, in pairs of a start and a length.
+    pub fn seed_ranges(&self) -> Vec<Range<usize>> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|c| c[0]..(c[0] + c[1]))
+            .collect()
+    }
+
+    /// Every stage composed into one function from seed to location.
+    pub fn function(&self) -> Piecewise {
+        self.map_collection
+            .iter()
+            .fold(Piecewise::identity(), |f, maps| {
+                f.then(&Piecewise::stage(maps))
+            })
+    }
+
+    /// The parts of `seeds` that end up at a location below `location`.
+    pub fn seeds_reaching(&self, seeds: &[Range<usize>], location: usize) -> Vec<Range<usize>> {
+        self.function()
+            .preimage(0..location)
+            .into_iter()
+            .flat_map(|inputs| {
+                seeds.iter().filter_map(move |seed| {
+                    let start = seed.start.max(inputs.start);
+                    let end = seed.end.min(inputs.end);
+                    (start < end).then_some(start..end)
+                })
+            })
+            .collect()
+    }
+}
+
+fn parse_map(l: &str) -> Result<Map, ParseError> {
+    match parse::numbers(l)?[..] {
+        [destination, source, length] => Ok(Map::new(destination, source, length)),
+        _ => Err(ParseError::new("expected three numbers", l)),
+    }
+}
+
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let (seed_section, sections) = parse::split_once(input, "\n\n")?;
+    let seeds_str = seed_section
+        .strip_prefix("seeds:")
+        .ok_or_else(|| ParseError::new("expected `seeds:`", seed_section))?;
+    let seeds: Vec<usize> = parse::numbers(seeds_str).map_err(|e| e.within(input, seeds_str))?;
+    // part two reads the seeds in pairs of a start and a length
+    match seeds_str.split_whitespace().last() {
+        None => {
+            return Err(ParseError::at(
+                input,
+                seeds_str,
+                "expected at least one seed",
+            ))
+        }
+        Some(last) if seeds.len() % 2 == 1 => {
+            return Err(ParseError::at(
+                input,
+                last,
+                "expected a length after this seed",
+            ))
+        }
+        _ => {}
+    }
+    let map_collection = sections
+        .split("\n\n")
+        .map(|s| {
+            let (_, maps) = parse::split_once(s, ":\n").map_err(|e| e.within(input, s))?;
+            parse::lines(maps, parse_map).map_err(|e| e.within(input, maps))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Almanac {
+        seeds,
+        map_collection,
+    })
+}
+
+pub fn part2(almanac: &Almanac) -> usize {
+    let seeds = almanac.seed_ranges();
+    almanac
+        .map_collection
+        .iter()
+        .fold(seeds, |ranges, maps| map_ranges(maps, ranges))
+        .iter()
+        .map(|range| range.start)
+        .min()
+        .expect("should have min item")
+}
+
+pub fn part1(almanac: &Almanac) -> usize {
+    solve(&almanac.seeds, &almanac.map_collection)
+}
+
+fn solve(seeds: &[usize], map_collection: &[Vec<Map>]) -> usize {
+    seeds
+        .par_iter()
+        .map(|s| {
+            let mut current = *s;
+            for map_group in map_collection.iter() {
+                let mut next = current;
+                for map in map_group.iter() {
+                    if let Some(partner) = map.partner(current) {
+                        next = partner;
+                        break;
+                    }
+                }
+                current = next;
+            }
+            current
+        })
+        .min()
+        .expect("should have min item")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input");
+        let result = part1(&parse(&input).unwrap());
+        assert_eq!(result, 35);
+    }
+
+    #[test]
+    fn test_seeds() {
+        let error = parse("seeds: 79 14 55\n\nmap:\n1 2 3\n").unwrap_err();
+        assert_eq!(error.message(), "expected a length after this seed");
+        assert_eq!((error.line(), error.column()), (1, 14));
+        let error = parse("seeds:\n\nmap:\n1 2 3\n").unwrap_err();
+        assert_eq!(error.message(), "expected at least one seed");
+    }
+
+    #[test]
+    fn test_map() {
+        let map = Map::new(52, 50, 48);
+        assert_eq!(map.partner(79), Some(81));
+        assert_eq!(map.intersect(&(40..60)), Some(50..60));
+        assert_eq!(map.intersect(&(98..120)), None);
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_split() {
+        let map = Map::new(52, 50, 48);
+        assert_eq!(map.split(40..60), (Some(52..62), vec![40..50]));
+        assert_eq!(map.split(40..110), (Some(52..100), vec![40..50, 98..110]));
+        assert_eq!(map.split(60..70), (Some(62..72), vec![]));
+        assert_eq!(map.split(0..10), (None, vec![0..10]));
+
+        let maps = [Map::new(52, 50, 48), Map::new(50, 98, 2)];
+        let mut ranges = map_ranges(&maps, vec![45..100]);
+        ranges.sort_by_key(|r| r.start);
+        assert_eq!(ranges, [45..50, 50..52, 52..100]);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input");
+        let result = part2(&parse(&input).unwrap());
+        assert_eq!(result, 46);
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_function() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input");
+        let almanac = parse(&input).unwrap();
+        let function = almanac.function();
+        let locations: Vec<_> = almanac.seeds.iter().map(|&s| function.eval(s)).collect();
+        assert_eq!(locations, [82, 43, 86, 35]);
+        assert!(function.invert(35).contains(&13));
+
+        let seeds: Vec<_> = almanac.seeds.iter().map(|&s| s..s + 1).collect();
+        assert_eq!(almanac.seeds_reaching(&seeds, 50), [13..14, 14..15]);
+        assert_eq!(almanac.seeds_reaching(&almanac.seed_ranges(), 47), [82..83]);
+        assert!(almanac
+            .seeds_reaching(&almanac.seed_ranges(), 46)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_overlapping_maps() {
+        // the second map starts first, but the first is listed first and wins the overlap
+        let input = "seeds: 7 12\n\nseed-to-soil map:\n100 10 10\n200 5 10\n";
+        let almanac = parse(input).unwrap();
+        let function = almanac.function();
+        assert_eq!((function.eval(7), function.eval(12)), (202, 102));
+        assert_eq!(part1(&almanac), 102);
+        assert_eq!(
+            (7..19).map(|s| function.eval(s)).min(),
+            Some(part2(&almanac))
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "seeds: 1 2\n\nseed-to-soil map:\n50 98 2\n52 50\n";
+        assert!(parse(input).is_err());
+    }
+}
@@ -0,0 +1,217 @@
+use std::{fmt, ops::Range};
+
+use crate::Map;
+
+/// The inputs `start..end`, which are all moved by `offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Piece {
+    pub start: usize,
+    pub end: usize,
+    pub offset: isize,
+}
+
+impl Piece {
+    fn apply(&self, x: usize) -> usize {
+        x.checked_add_signed(self.offset)
+            .expect("mapped values should fit in a usize")
+    }
+
+    /// The outputs of this piece.
+    pub fn image(&self) -> Range<usize> {
+        self.apply(self.start)..self.apply(self.end)
+    }
+}
+
+/// A function over every `usize` made of pieces that each shift a run of inputs by a
+/// constant. The pieces are sorted, don't overlap and leave no gaps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Piecewise {
+    pieces: Vec<Piece>,
+}
+
+impl Piecewise {
+    pub fn identity() -> Self {
+        Self {
+            pieces: vec![Piece {
+                start: 0,
+                end: usize::MAX,
+                offset: 0,
+            }],
+        }
+    }
+
+    /// One stage of an almanac, where inputs no map covers are left as they are. Where maps
+    /// overlap, the one listed first wins, as it does when seeds are sent through the maps.
+    pub(crate) fn stage(maps: &[Map]) -> Self {
+        let mut claimed = Vec::new();
+        for (i, map) in maps.iter().enumerate() {
+            let offset = map.destination as isize - map.source as isize;
+            let inputs = map.source..map.source + map.length;
+            let free = maps[..i].iter().fold(vec![inputs], |free, earlier| {
+                free.into_iter().flat_map(|r| earlier.split(r).1).collect()
+            });
+            claimed.extend(free.into_iter().filter(|r| !r.is_empty()).map(|r| Piece {
+                start: r.start,
+                end: r.end,
+                offset,
+            }));
+        }
+        claimed.sort_by_key(|piece| piece.start);
+
+        let mut pieces = Vec::new();
+        let mut covered = 0;
+        for piece in claimed {
+            if covered < piece.start {
+                pieces.push(Piece {
+                    start: covered,
+                    end: piece.start,
+                    offset: 0,
+                });
+            }
+            covered = piece.end;
+            pieces.push(piece);
+        }
+        pieces.push(Piece {
+            start: covered,
+            end: usize::MAX,
+            offset: 0,
+        });
+        Self::merged(pieces)
+    }
+
+    /// Joins neighbouring pieces with the same offset.
+    fn merged(pieces: Vec<Piece>) -> Self {
+        let mut merged: Vec<Piece> = Vec::with_capacity(pieces.len());
+        for piece in pieces {
+            match merged.last_mut() {
+                Some(last) if last.end == piece.start && last.offset == piece.offset => {
+                    last.end = piece.end;
+                }
+                _ => merged.push(piece),
+            }
+        }
+        Self { pieces: merged }
+    }
+
+    pub fn pieces(&self) -> &[Piece] {
+        &self.pieces
+    }
+
+    /// This function followed by `next`.
+    pub fn then(&self, next: &Piecewise) -> Self {
+        let mut pieces = Vec::new();
+        for piece in &self.pieces {
+            let image = piece.image();
+            for (start, end, offset) in next.split(image) {
+                pieces.push(Piece {
+                    start: (start as isize - piece.offset) as usize,
+                    end: (end as isize - piece.offset) as usize,
+                    offset: piece.offset + offset,
+                });
+            }
+        }
+        pieces.sort_by_key(|piece| piece.start);
+        Self::merged(pieces)
+    }
+
+    /// The parts of `range` each piece covers, with that piece's offset.
+    fn split(&self, range: Range<usize>) -> impl Iterator<Item = (usize, usize, isize)> + '_ {
+        let first = self
+            .pieces
+            .partition_point(|piece| piece.end <= range.start);
+        self.pieces[first..]
+            .iter()
+            .take_while(move |piece| piece.start < range.end)
+            .map(move |piece| {
+                let start = piece.start.max(range.start);
+                let end = piece.end.min(range.end);
+                (start, end, piece.offset)
+            })
+    }
+
+    pub fn eval(&self, x: usize) -> usize {
+        let i = self.pieces.partition_point(|piece| piece.end <= x);
+        self.pieces[i].apply(x)
+    }
+
+    /// Every input that maps into `outputs`, as sorted ranges.
+    pub fn preimage(&self, outputs: Range<usize>) -> Vec<Range<usize>> {
+        let mut inputs: Vec<_> = self
+            .pieces
+            .iter()
+            .filter_map(|piece| {
+                let image = piece.image();
+                let start = image.start.max(outputs.start);
+                let end = image.end.min(outputs.end);
+                (start < end).then(|| {
+                    (start as isize - piece.offset) as usize..(end as isize - piece.offset) as usize
+                })
+            })
+            .collect();
+        inputs.sort_by_key(|range| range.start);
+        inputs
+    }
+
+    /// Every input that maps to `y`.
+    pub fn invert(&self, y: usize) -> Vec<usize> {
+        self.preimage(y..y + 1)
+            .into_iter()
+            .map(|range| range.start)
+            .collect()
+    }
+}
+
+impl fmt::Display for Piecewise {
+    /// One line per piece, such as `50..98 +2`, leaving off the end of the last piece.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for piece in &self.pieces {
+            if piece.end == usize::MAX {
+                write!(f, "{}..", piece.start)?;
+            } else {
+                write!(f, "{}..{}", piece.start, piece.end)?;
+            }
+            writeln!(f, " {:+}", piece.offset)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage() {
+        let stage = Piecewise::stage(&[Map::new(50, 98, 2), Map::new(52, 50, 48)]);
+        assert_eq!(
+            stage.to_string(),
+            "0..50 +0\n50..98 +2\n98..100 -48\n100.. +0\n"
+        );
+        assert_eq!(stage.eval(79), 81);
+        assert_eq!(stage.eval(99), 51);
+        assert_eq!(stage.eval(1000), 1000);
+        assert_eq!(stage.invert(51), [99]);
+        assert_eq!(stage.invert(52), [50]);
+        assert_eq!(stage.invert(100), [100]);
+
+        // `10..20` is listed first, so it keeps the overlap though `5..15` starts earlier
+        let overlapping = Piecewise::stage(&[Map::new(100, 10, 10), Map::new(200, 5, 10)]);
+        assert_eq!(overlapping.eval(12), 102);
+        assert_eq!(overlapping.eval(7), 202);
+        assert_eq!(overlapping.eval(20), 20);
+    }
+
+    #[test]
+    fn test_then() {
+        let swap = Piecewise::stage(&[Map::new(10, 0, 5), Map::new(0, 10, 5)]);
+        let both = swap.then(&swap);
+        assert_eq!(both, Piecewise::identity());
+
+        let shift = Piecewise::stage(&[Map::new(3, 0, 2)]);
+        let composed = swap.then(&shift);
+        assert_eq!(composed.eval(10), 3);
+        assert_eq!(composed.eval(0), 10);
+        assert_eq!(composed.invert(3), [10, 13]);
+        assert!(composed.invert(0).is_empty());
+    }
+}
@@ -0,0 +1,50 @@
+//! Evaluates a motion model on a race file, printing the winning holds of each race.
+//!
+//! Usage: `races [--model linear|quadratic|capped:N|decay:N] [--joined] [PATH|-]`
+
+use std::{env, path::Path, process};
+
+use common::input::{self, Source};
+use day06::motion::{self, Model};
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut model = Model::Linear;
+    let mut joined = false;
+    let mut rest = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--model" | "-m" => {
+                model = args.next().ok_or("--model expects a model")?.parse()?;
+            }
+            "--joined" => joined = true,
+            _ => rest.push(arg.clone()),
+        }
+    }
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let source: Source = input::from_args(6, dir, &rest)?;
+    let text = source.read()?;
+    let races = day06::parse(&text).map_err(|e| e.render(&source.to_string(), &text))?;
+
+    let races: Vec<(u128, u128)> = if joined {
+        vec![races.joined()]
+    } else {
+        races.races().map(|(t, r)| (t as u128, r as u128)).collect()
+    };
+    for (time, record) in races {
+        let ms = u64::try_from(time).map_err(|_| format!("{time} ms is too long a race"))?;
+        match motion::window(&model, ms, record) {
+            Some(window) => println!("{time} ms, record {record}: {window}"),
+            None => println!("{time} ms, record {record}: no hold wins"),
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(e) = run(&args) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
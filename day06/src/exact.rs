@@ -0,0 +1,126 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use num_bigint::BigUint;
+
+/// An unsigned integer wide enough to hold a race's time squared.
+pub trait Integer:
+    Clone
+    + Ord
+    + From<u8>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// The largest integer whose square is at most `self`.
+    fn isqrt(&self) -> Self;
+}
+
+impl Integer for u64 {
+    fn isqrt(&self) -> Self {
+        u64::isqrt(*self)
+    }
+}
+
+impl Integer for u128 {
+    fn isqrt(&self) -> Self {
+        u128::isqrt(*self)
+    }
+}
+
+impl Integer for BigUint {
+    fn isqrt(&self) -> Self {
+        self.sqrt()
+    }
+}
+
+fn wins<T: Integer>(time: &T, record: &T, hold: &T) -> bool {
+    hold.clone() * (time.clone() - hold.clone()) > *record
+}
+
+/// The shortest and longest holds, in `0..=time`, that go further than `record`.
+///
+/// The holds that win lie strictly between the roots of `h² - time·h + record`, so the
+/// integer square root of the discriminant gives the shortest to within one, which is then
+/// corrected by checking its neighbours. The longest mirrors it around `time / 2`.
+pub fn winning_holds<T: Integer>(time: T, record: T) -> Option<(T, T)> {
+    let one = T::from(1);
+    let two = T::from(2);
+    let squared = time.clone() * time.clone();
+    let four_record = T::from(4) * record.clone();
+    if squared <= four_record {
+        return None;
+    }
+    let root = (squared - four_record).isqrt();
+    let mut shortest = (time.clone() - root) / two;
+    while shortest.clone() > T::from(0) && wins(&time, &record, &(shortest.clone() - one.clone())) {
+        shortest = shortest - one.clone();
+    }
+    while shortest.clone() + shortest.clone() <= time && !wins(&time, &record, &shortest) {
+        shortest = shortest + one.clone();
+    }
+    let longest = time - shortest.clone();
+    (shortest <= longest).then_some((shortest, longest))
+}
+
+/// How many holds go further than `record`.
+pub fn count_wins<T: Integer>(time: T, record: T) -> T {
+    match winning_holds(time, record) {
+        Some((shortest, longest)) => longest - shortest + T::from(1),
+        None => T::from(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn brute_force(time: u64, record: u64) -> u64 {
+        (0..=time).filter(|h| h * (time - h) > record).count() as u64
+    }
+
+    #[test]
+    fn test_count_wins() {
+        assert_eq!(count_wins(7u64, 9), 4);
+        assert_eq!(count_wins(30u64, 200), 9);
+        assert_eq!(count_wins(4u64, 4), 0);
+        assert_eq!(count_wins(3u64, 2), 0);
+        assert_eq!(winning_holds(71530u64, 940200), Some((14, 71516)));
+    }
+
+    #[test]
+    fn test_beyond_f64() {
+        // a hold of 3 exactly ties the record, which an f64 can't tell from a win once
+        // time² is past 2^53
+        let time = 1_000_000_007u64;
+        let record = 3 * (time - 3);
+        assert_eq!(winning_holds(time, record), Some((4, time - 4)));
+        assert_eq!(count_wins(time as u128, record as u128), 1_000_000_000);
+        assert_eq!(
+            count_wins(BigUint::from(time), BigUint::from(record)),
+            BigUint::from(1_000_000_000u32)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(time in 0u64..2000, record in 0u64..1_000_000) {
+            prop_assert_eq!(count_wins(time, record), brute_force(time, record));
+            prop_assert_eq!(count_wins(time as u128, record as u128), brute_force(time, record) as u128);
+            prop_assert_eq!(
+                count_wins(BigUint::from(time), BigUint::from(record)),
+                BigUint::from(brute_force(time, record))
+            );
+        }
+
+        #[test]
+        fn bounds_are_tight(time in 0u64..u32::MAX as u64, record in 0u64..u64::MAX / 4) {
+            if let Some((shortest, longest)) = winning_holds(time as u128, record as u128) {
+                prop_assert!(wins(&(time as u128), &(record as u128), &shortest));
+                prop_assert!(wins(&(time as u128), &(record as u128), &longest));
+                prop_assert!(shortest == 0 || !wins(&(time as u128), &(record as u128), &(shortest - 1)));
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::num::{IntErrorKind, ParseIntError};
+
+use num_bigint::BigUint;
+
+use common::{parse, ParseError, Part, Solution};
+
+mod exact;
+pub mod motion;
+
+pub use exact::{count_wins, winning_holds, Integer};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Races;
+    type Params = ();
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input)
+    }
+}
+
+#[derive(Debug)]
+pub struct Races {
+    times: Vec<usize>,
+    records: Vec<usize>,
+    joined: (u128, u128),
+}
+
+impl Races {
+    /// Each race's time and record distance.
+    pub fn races(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.times.iter().copied().zip(self.records.iter().copied())
+    }
+
+    /// The single race part two reads, with the spaces between numbers ignored.
+    pub fn joined(&self) -> (u128, u128) {
+        self.joined
+    }
+}
+
+pub fn parse(input: &str) -> Result<Races, ParseError> {
+    let (time_str, records_str) = parse::split_once(input.trim_end(), "\n")?;
+    let (times, time) = parse_line(time_str).map_err(|e| e.within(input, time_str))?;
+    let (records, record) = parse_line(records_str).map_err(|e| e.within(input, records_str))?;
+    if times.len() != records.len() {
+        return Err(ParseError::at(
+            input,
+            records_str,
+            format!("expected {} records, found {}", times.len(), records.len()),
+        ));
+    }
+    Ok(Races {
+        times,
+        records,
+        joined: (time, record),
+    })
+}
+
+pub fn part1(races: &Races) -> u128 {
+    races
+        .races()
+        .map(|(t, r)| count_wins(t as u128, r as u128))
+        .product()
+}
+
+/// Solved with big integers, as the joined time squared needn't fit in a `u128`.
+pub fn part2(races: &Races) -> u128 {
+    let (time, record) = races.joined;
+    let wins = count_wins(BigUint::from(time), BigUint::from(record));
+    u128::try_from(wins).expect("there are no more wins than ms in the race")
+}
+
+/// The numbers in `list` read as one number, ignoring the spaces between them.
+fn join_digits(list: &str) -> Result<u128, ParseError> {
+    let digits: String = list.split_whitespace().collect();
+    digits.parse().map_err(|e: ParseIntError| {
+        let message = match e.kind() {
+            IntErrorKind::Empty => "expected at least one number",
+            _ => "joined number is too large",
+        };
+        ParseError::new(message, list)
+    })
+}
+
+/// The numbers on a line, and those numbers joined into one.
+fn parse_line(line: &str) -> Result<(Vec<usize>, u128), ParseError> {
+    let (_, list) = parse::split_once(line, ":")?;
+    let numbers = parse::numbers(list).map_err(|e| e.within(line, list))?;
+    let joined = join_digits(list).map_err(|e| e.within(line, list))?;
+    Ok((numbers, joined))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// The float solver this crate used to use, kept to check the exact one against.
+    fn number_possible_records(time: f64, record: f64) -> usize {
+        let epsilon = 1e-10;
+        let discriminant = (time * time - 4.0 * record).sqrt();
+        let root1 = (time + discriminant) / 2.0 - epsilon;
+        let root2 = (time - discriminant) / 2.0 + epsilon;
+        (root1.floor() - root2.ceil() + 1.0) as usize
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read input file");
+        let result = part1(&parse(&input).unwrap());
+        assert_eq!(result, 288);
+    }
+
+    #[test]
+    fn test_number_possible_records() {
+        assert_eq!(number_possible_records(7.0, 9.0), 4);
+        assert_eq!(number_possible_records(15.0, 40.0), 8);
+        assert_eq!(number_possible_records(30.0, 200.0), 9);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read input file");
+        let result = part2(&parse(&input).unwrap());
+        assert_eq!(result, 71503);
+    }
+
+    #[test]
+    fn test_joined() {
+        // the joined time is past 2^64, so its square is past 2^128
+        let races = parse("Time: 10000000000 0000000000\nDistance: 0 1\n").unwrap();
+        assert_eq!(races.joined(), (10u128.pow(20), 1));
+        assert_eq!(part2(&races), 10u128.pow(20) - 1);
+
+        let long = format!("Time: {}\nDistance: 1\n", "9 ".repeat(40));
+        let error = parse(&long).unwrap_err();
+        assert_eq!(error.message(), "joined number is too large");
+        assert_eq!((error.line(), error.column()), (1, 6));
+        assert!(parse("Time:\nDistance:\n").is_err());
+    }
+
+    #[test]
+    fn test_matches_float() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read input file");
+        let races = parse(&input).unwrap();
+        for (&t, &r) in races.times.iter().zip(&races.records) {
+            assert_eq!(
+                count_wins(t as u64, r as u64) as usize,
+                number_possible_records(t as f64, r as f64)
+            );
+        }
+        let (time, record) = races.joined();
+        assert_eq!(
+            count_wins(time, record) as usize,
+            number_possible_records(time as f64, record as f64)
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use std::{fmt, str::FromStr};
+
+/// How far a boat goes in a race of `time` ms after holding its button for `hold` ms.
+///
+/// The distance must rise strictly as the hold gets longer until it peaks, and never rise
+/// again after that, which lets the winning holds be found by binary search.
+pub trait Motion {
+    fn distance(&self, hold: u64, time: u64) -> u128;
+}
+
+/// The puzzle's boat, which gains 1 mm/ms of speed for each ms held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Linear;
+
+impl Motion for Linear {
+    fn distance(&self, hold: u64, time: u64) -> u128 {
+        hold as u128 * (time - hold) as u128
+    }
+}
+
+/// A boat whose speed grows with the square of the hold. Distances too far to fit in a
+/// `u128` saturate, so they beat any record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quadratic;
+
+impl Motion for Quadratic {
+    fn distance(&self, hold: u64, time: u64) -> u128 {
+        let hold = hold as u128;
+        (hold * hold).saturating_mul(time as u128 - hold)
+    }
+}
+
+/// A linear boat that can't go faster than `top` mm/ms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capped {
+    pub top: u64,
+}
+
+impl Motion for Capped {
+    fn distance(&self, hold: u64, time: u64) -> u128 {
+        hold.min(self.top) as u128 * (time - hold) as u128
+    }
+}
+
+/// A linear boat whose charge leaks once it is let go, losing 1 mm/ms of speed every
+/// `period` ms until it stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decay {
+    pub period: u64,
+}
+
+impl Motion for Decay {
+    fn distance(&self, hold: u64, time: u64) -> u128 {
+        let (speed, period) = (hold as u128, self.period as u128);
+        let moving = (time - hold) as u128;
+        let (slowdowns, rest) = (moving / period, moving % period);
+        if slowdowns >= speed {
+            period * speed * (speed + 1) / 2
+        } else {
+            // a whole period at each speed from `speed` down, then what's left at the next
+            period * slowdowns * (2 * speed + 1 - slowdowns) / 2 + rest * (speed - slowdowns)
+        }
+    }
+}
+
+/// The models that can be named on the command line, as `linear`, `quadratic`, `capped:N` or
+/// `decay:N`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Model {
+    Linear,
+    Quadratic,
+    Capped(Capped),
+    Decay(Decay),
+}
+
+impl Motion for Model {
+    fn distance(&self, hold: u64, time: u64) -> u128 {
+        match self {
+            Model::Linear => Linear.distance(hold, time),
+            Model::Quadratic => Quadratic.distance(hold, time),
+            Model::Capped(m) => m.distance(hold, time),
+            Model::Decay(m) => m.distance(hold, time),
+        }
+    }
+}
+
+impl FromStr for Model {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, arg) = match s.split_once(':') {
+            Some((name, arg)) => {
+                let arg = arg
+                    .parse::<u64>()
+                    .map_err(|e| format!("invalid argument to {name}: {e}"))?;
+                (name, Some(arg))
+            }
+            None => (s, None),
+        };
+        match (name, arg) {
+            ("linear", None) => Ok(Model::Linear),
+            ("quadratic", None) => Ok(Model::Quadratic),
+            ("capped", Some(top)) if top > 0 => Ok(Model::Capped(Capped { top })),
+            ("decay", Some(period)) if period > 0 => Ok(Model::Decay(Decay { period })),
+            ("capped" | "decay", _) => Err(format!("{name} expects a positive number")),
+            _ => Err(format!("invalid model: {s}")),
+        }
+    }
+}
+
+/// The holds that beat a record, and the hold that goes furthest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Window {
+    pub shortest: u64,
+    pub longest: u64,
+    pub best: u64,
+    pub furthest: u128,
+}
+
+impl Window {
+    pub fn count(&self) -> u64 {
+        self.longest - self.shortest + 1
+    }
+}
+
+impl fmt::Display for Window {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "holds {}..={} ({} ways), best {} going {}",
+            self.shortest,
+            self.longest,
+            self.count(),
+            self.best,
+            self.furthest
+        )
+    }
+}
+
+/// The first hold in `low..high` for which `f` is false, given it is true for every hold
+/// before that and false for every one after.
+fn partition_point(mut low: u64, mut high: u64, f: impl Fn(u64) -> bool) -> u64 {
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if f(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// The holds under `motion` that go further than `record` in a race of `time` ms, if any do.
+pub fn window(motion: &impl Motion, time: u64, record: u128) -> Option<Window> {
+    let distance = |hold| motion.distance(hold, time);
+    let best = partition_point(0, time, |h| distance(h) < distance(h + 1));
+    let furthest = distance(best);
+    if furthest <= record {
+        return None;
+    }
+    let shortest = partition_point(0, best, |h| distance(h) <= record);
+    let longest = if distance(time) > record {
+        time
+    } else {
+        partition_point(best, time, |h| distance(h) > record) - 1
+    };
+    Some(Window {
+        shortest,
+        longest,
+        best,
+        furthest,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::count_wins;
+    use proptest::prelude::*;
+
+    fn brute_force(motion: &impl Motion, time: u64, record: u128) -> Option<Window> {
+        let wins: Vec<_> = (0..=time)
+            .filter(|&h| motion.distance(h, time) > record)
+            .collect();
+        let best = (0..=time).max_by_key(|&h| (motion.distance(h, time), u64::MAX - h))?;
+        Some(Window {
+            shortest: *wins.first()?,
+            longest: *wins.last()?,
+            best,
+            furthest: motion.distance(best, time),
+        })
+    }
+
+    #[test]
+    fn test_window() {
+        let window = window(&Linear, 30, 200).unwrap();
+        assert_eq!(
+            window.to_string(),
+            "holds 11..=19 (9 ways), best 15 going 225"
+        );
+        assert_eq!(super::window(&Linear, 30, 225), None);
+
+        let capped = super::window(&Capped { top: 5 }, 30, 100).unwrap();
+        assert_eq!((capped.shortest, capped.longest, capped.best), (4, 9, 5));
+    }
+
+    #[test]
+    fn test_decay() {
+        // speed 3 for 2 ms, then 2 for 2 ms, then 1 for the last ms
+        assert_eq!(Decay { period: 2 }.distance(3, 8), 3 * 2 + 2 * 2 + 1);
+        assert_eq!(Decay { period: 2 }.distance(3, 100), 2 * (3 + 2 + 1));
+        assert_eq!(Decay { period: 1 }.distance(0, 10), 0);
+    }
+
+    #[test]
+    fn test_model() {
+        assert_eq!("linear".parse(), Ok(Model::Linear));
+        assert_eq!("capped:40".parse(), Ok(Model::Capped(Capped { top: 40 })));
+        assert!("decay:0".parse::<Model>().is_err());
+        assert!("capped:0".parse::<Model>().is_err());
+        assert!("capped".parse::<Model>().is_err());
+        assert!("warp".parse::<Model>().is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(
+            model in prop_oneof![
+                Just(Model::Linear),
+                Just(Model::Quadratic),
+                (1u64..50).prop_map(|top| Model::Capped(Capped { top })),
+                (1u64..10).prop_map(|period| Model::Decay(Decay { period })),
+            ],
+            time in 0u64..200,
+            record in 0u128..50_000,
+        ) {
+            let expected = brute_force(&model, time, record);
+            prop_assert_eq!(window(&model, time, record), expected);
+        }
+
+        #[test]
+        fn quadratic_near_max(time in u64::MAX - 1000.., record: u128) {
+            let distance = |hold| Quadratic.distance(hold, time);
+            if let Some(w) = window(&Quadratic, time, record) {
+                prop_assert!(distance(w.shortest) > record && distance(w.longest) > record);
+                prop_assert!(w.shortest == 0 || distance(w.shortest - 1) <= record);
+                prop_assert!(w.longest == time || distance(w.longest + 1) <= record);
+            } else {
+                prop_assert!(distance(time / 3 * 2) <= record);
+            }
+        }
+
+        #[test]
+        fn linear_matches_exact(time in 0u64..1_000_000, record in 0u64..250_000_000_000) {
+            let count = window(&Linear, time, record as u128).map_or(0, |w| w.count());
+            prop_assert_eq!(count, count_wins(time, record));
+        }
+    }
+}
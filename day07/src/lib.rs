@@ -0,0 +1,419 @@
+use itertools::Itertools;
+
+use common::{parse, ParseError, Part, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Bids;
+    type Params = ();
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input)
+    }
+}
+
+const CARDS: &str = "23456789TJQKA";
+
+/// The puzzle's bids, with the hands ranked under the rules of each part.
+#[derive(Debug, Clone)]
+pub struct Bids {
+    standard: Vec<(Hand, usize)>,
+    jokers: Vec<(Hand, usize)>,
+}
+
+pub fn parse(input: &str) -> Result<Bids, ParseError> {
+    Ok(Bids {
+        standard: parse_with(input, &Rules::standard())?,
+        jokers: parse_with(input, &Rules::jokers())?,
+    })
+}
+
+/// Parses hands and bids, ranking each hand under `rules`.
+pub fn parse_with(input: &str, rules: &Rules) -> Result<Vec<(Hand, usize)>, ParseError> {
+    parse::lines(input, |l| {
+        let (cards, bid_str) = parse::split_once(l, " ")?;
+        let hand = rules.hand(cards).map_err(|e| e.within(l, cards))?;
+        let bid = parse::number(bid_str).map_err(|e| e.within(l, bid_str))?;
+        Ok((hand, bid))
+    })
+}
+
+/// The kinds of hand, weakest first. Straights and flushes are only made under rules that
+/// allow them, so the puzzle's own rules use just the rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// The best type a hand can make, given how many of each natural rank it holds and how
+    /// many wildcards.
+    pub fn from_counts(mut counts: Vec<usize>, wildcards: usize) -> Self {
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        match counts.first_mut() {
+            Some(most) => *most += wildcards,
+            None => counts.push(wildcards),
+        }
+        match (counts[0], counts.get(1).copied().unwrap_or(0)) {
+            (5.., _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2..) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+/// How hands that are the same type are ordered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Card by card, in the order they were dealt.
+    #[default]
+    InOrder,
+    /// Card by card, strongest first.
+    Highest,
+    /// By the ranks held most often, then the strongest, as poker breaks ties.
+    Groups,
+}
+
+/// How cards are ranked: `order` lists them from weakest to strongest, and any of
+/// `wildcards` stands in for whatever card makes the best hand.
+///
+/// Hands hold `size` cards. When `suits` isn't empty, each card is a rank followed by one of
+/// them, such as `Th`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    pub order: Vec<char>,
+    pub wildcards: Vec<char>,
+    pub size: usize,
+    pub suits: Vec<char>,
+    /// Whether ranks that are all next to each other in `order` make a straight.
+    pub straights: bool,
+    /// Whether cards that all share a suit make a flush, which needs `suits`.
+    pub flushes: bool,
+    pub tie_break: TieBreak,
+}
+
+impl Rules {
+    pub fn new(order: &str, wildcards: &str) -> Self {
+        Self {
+            order: order.chars().collect(),
+            wildcards: wildcards.chars().collect(),
+            size: 5,
+            suits: Vec::new(),
+            straights: false,
+            flushes: false,
+            tie_break: TieBreak::InOrder,
+        }
+    }
+
+    /// Part one's rules, where `J` is a jack.
+    pub fn standard() -> Self {
+        Self::new(CARDS, "")
+    }
+
+    /// Part two's rules, where `J` is a joker, the weakest card but wild.
+    pub fn jokers() -> Self {
+        Self::new("J23456789TQKA", "J")
+    }
+
+    /// Rules closer to poker, with suited cards, straights and flushes, and poker's
+    /// tie-breaks. Unlike poker, aces only play high, so `A2345` isn't a straight.
+    pub fn poker() -> Self {
+        Self {
+            suits: "cdhs".chars().collect(),
+            straights: true,
+            flushes: true,
+            tie_break: TieBreak::Groups,
+            ..Self::standard()
+        }
+    }
+
+    fn strength(&self, card: char) -> Option<usize> {
+        self.order.iter().position(|&c| c == card)
+    }
+
+    /// The rank and suit of each card in `cards`.
+    fn cards(&self, cards: &str) -> Result<Vec<(char, Option<char>)>, ParseError> {
+        let width = if self.suits.is_empty() { 1 } else { 2 };
+        let tokens: Vec<_> = cards
+            .char_indices()
+            .step_by(width)
+            .map(|(i, _)| {
+                let end = cards[i..]
+                    .char_indices()
+                    .nth(width)
+                    .map_or(cards.len(), |(j, _)| i + j);
+                &cards[i..end]
+            })
+            .collect();
+        if tokens.len() != self.size {
+            return Err(ParseError::new(
+                format!("expected {} cards, found {}", self.size, tokens.len()),
+                cards,
+            ));
+        }
+        tokens
+            .into_iter()
+            .map(|token| {
+                let mut chars = token.chars();
+                let rank = chars.next().filter(|&c| self.strength(c).is_some());
+                let suit = chars.next();
+                match (rank, suit) {
+                    (Some(rank), None) if self.suits.is_empty() => Ok((rank, None)),
+                    (Some(rank), Some(suit)) if self.suits.contains(&suit) => {
+                        Ok((rank, Some(suit)))
+                    }
+                    _ => Err(ParseError::at(cards, token, "invalid card")),
+                }
+            })
+            .collect()
+    }
+
+    fn hand_type(&self, cards: &[(char, Option<char>)]) -> HandType {
+        let natural: Vec<_> = cards
+            .iter()
+            .filter(|(rank, _)| !self.wildcards.contains(rank))
+            .collect();
+        let wildcards = cards.len() - natural.len();
+        let counts = natural.iter().counts_by(|(rank, _)| rank);
+        let mut hand_type = HandType::from_counts(counts.into_values().collect(), wildcards);
+
+        // wildcards fill any gaps, so a straight just needs distinct natural ranks close
+        // enough together
+        let strengths: Vec<_> = natural
+            .iter()
+            .filter_map(|(rank, _)| self.strength(*rank))
+            .sorted()
+            .collect();
+        let straight = self.straights
+            && strengths.iter().all_unique()
+            && strengths
+                .first()
+                .zip(strengths.last())
+                .is_none_or(|(low, high)| high - low < cards.len());
+        let flush = self.flushes
+            && !self.suits.is_empty()
+            && natural.iter().map(|(_, suit)| suit).all_equal();
+        if straight {
+            hand_type = hand_type.max(HandType::Straight);
+        }
+        if flush {
+            hand_type = hand_type.max(HandType::Flush);
+        }
+        if straight && flush {
+            hand_type = hand_type.max(HandType::StraightFlush);
+        }
+        hand_type
+    }
+
+    /// The strength of each card, in the order `tie_break` compares them.
+    fn tie_break(&self, cards: &[(char, Option<char>)]) -> Vec<usize> {
+        let strength = |rank| self.strength(rank).expect("ranks are checked on parsing");
+        let strengths = cards.iter().map(|&(rank, _)| strength(rank));
+        match self.tie_break {
+            TieBreak::InOrder => strengths.collect(),
+            TieBreak::Highest => strengths.sorted_by(|a, b| b.cmp(a)).collect(),
+            TieBreak::Groups => {
+                let counts = strengths.clone().counts();
+                strengths
+                    .sorted_by_key(|s| std::cmp::Reverse((counts[s], *s)))
+                    .collect()
+            }
+        }
+    }
+
+    /// The hand made by `cards`, which must hold `size` cards that are all in `order`.
+    pub fn hand(&self, cards: &str) -> Result<Hand, ParseError> {
+        let parsed = self.cards(cards)?;
+        Ok(Hand {
+            hand_type: self.hand_type(&parsed),
+            strengths: self.tie_break(&parsed),
+            cards: cards.to_string(),
+        })
+    }
+}
+
+/// A hand ranked under some `Rules`, first by its type and then by its cards as the rules'
+/// `tie_break` orders them. Hands are only comparable when made by the same rules.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Hand {
+    pub hand_type: HandType,
+    strengths: Vec<usize>,
+    pub cards: String,
+}
+
+/// The total winnings, where each hand wins its bid times its rank. The hands should all be
+/// ranked under the same rules.
+pub fn winnings(hands: &[(Hand, usize)]) -> usize {
+    hands
+        .iter()
+        .sorted()
+        .enumerate()
+        .map(|(i, (_, bid))| bid * (i + 1))
+        .sum()
+}
+
+pub fn part1(bids: &Bids) -> usize {
+    winnings(&bids.standard)
+}
+
+pub fn part2(bids: &Bids) -> usize {
+    winnings(&bids.jokers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_hand() {
+        let rules = Rules::standard();
+        let hand = |cards| rules.hand(cards).unwrap();
+        assert_eq!(hand("AAAAA").hand_type, HandType::FiveOfAKind);
+        assert_eq!(hand("AKAAA").hand_type, HandType::FourOfAKind);
+        assert!(hand("AKAAA") < hand("AAAAA"));
+        assert!(hand("33332") > hand("2AAAA"));
+        assert!(hand("77888") > hand("77788"));
+        assert!(rules.hand("AKAAX").is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read input");
+        assert_eq!(part1(&parse(&input).unwrap()), 6440);
+    }
+
+    #[test]
+    fn test_jokers() {
+        let rules = Rules::jokers();
+        let hand = |cards| rules.hand(cards).unwrap();
+        assert_eq!(hand("AAAJJ").hand_type, HandType::FiveOfAKind);
+        assert_eq!(hand("AKAAA").hand_type, HandType::FourOfAKind);
+        assert_eq!(hand("2J3J4").hand_type, HandType::ThreeOfAKind);
+        assert_eq!(hand("JJJJJ").hand_type, HandType::FiveOfAKind);
+        assert!(hand("JJJJJ") < hand("22222"));
+        assert!(hand("JKKK2") < hand("QQQQ2"));
+    }
+
+    #[test]
+    fn test_wildcards() {
+        let rules = Rules::new("23456789TJQKA", "2A");
+        let hand = rules.hand("A2KQT").unwrap();
+        assert_eq!(hand.hand_type, HandType::ThreeOfAKind);
+        assert_eq!(HandType::from_counts(vec![1, 1, 1], 0), HandType::HighCard);
+        assert_eq!(HandType::from_counts(vec![2, 1], 2), HandType::FourOfAKind);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read input");
+        assert_eq!(part2(&parse(&input).unwrap()), 5905);
+    }
+
+    #[test]
+    fn test_straights_and_flushes() {
+        let rules = Rules::poker();
+        let hand = |cards| rules.hand(cards).unwrap();
+        assert_eq!(hand("9hThJhQhKh").hand_type, HandType::StraightFlush);
+        assert_eq!(hand("9hThJcQhKh").hand_type, HandType::Straight);
+        assert_eq!(hand("2h5h9hQhKh").hand_type, HandType::Flush);
+        assert_eq!(hand("2h2c2s5h5d").hand_type, HandType::FullHouse);
+        assert!(hand("2h5h9hQhKh") > hand("9hThJcQhKh"));
+        assert!(rules.hand("2h5h9hQhK").is_err());
+        assert!(rules.hand("2x5h9hQhKh").is_err());
+
+        let wild = Rules {
+            wildcards: vec!['J'],
+            ..Rules::poker()
+        };
+        assert_eq!(
+            wild.hand("9hJcJhQhKh").unwrap().hand_type,
+            HandType::StraightFlush
+        );
+        assert_eq!(wild.hand("2hJc5h8hKh").unwrap().hand_type, HandType::Flush);
+        assert_eq!(hand("Ah2c3h4h5h").hand_type, HandType::HighCard);
+
+        let unsuited = Rules {
+            flushes: true,
+            ..Rules::standard()
+        };
+        assert_eq!(
+            unsuited.hand("2593K").unwrap().hand_type,
+            HandType::HighCard
+        );
+    }
+
+    #[test]
+    fn test_hand_sizes() {
+        let three = Rules {
+            size: 3,
+            ..Rules::standard()
+        };
+        let hand = |cards| three.hand(cards).unwrap();
+        assert_eq!(hand("AAA").hand_type, HandType::ThreeOfAKind);
+        assert_eq!(hand("AKA").hand_type, HandType::OnePair);
+        assert!(three.hand("AAAAA").is_err());
+
+        let seven = Rules {
+            size: 7,
+            ..Rules::jokers()
+        };
+        assert_eq!(
+            seven.hand("AAAKKJ2").unwrap().hand_type,
+            HandType::FourOfAKind
+        );
+        let input = "AAA2 10\n23Q4 5\n";
+        let four = Rules {
+            size: 4,
+            ..Rules::standard()
+        };
+        assert_eq!(winnings(&parse_with(input, &four).unwrap()), 25);
+        assert!(parse(input).is_err());
+
+        let poker = parse_with("2h5h9hQhKh 3\n9hThJcQhKh 4\n", &Rules::poker()).unwrap();
+        assert_eq!(winnings(&poker), 4 + 3 * 2);
+    }
+
+    #[test]
+    fn test_tie_breaks() {
+        let rules = |tie_break| Rules {
+            tie_break,
+            ..Rules::standard()
+        };
+        let compare = |rules: Rules, a, b| rules.hand(a).unwrap().cmp(&rules.hand(b).unwrap());
+        assert!(compare(rules(TieBreak::InOrder), "KK677", "KTJJT").is_gt());
+        assert!(compare(rules(TieBreak::Highest), "2AKQ3", "AKQ4J").is_lt());
+        assert!(compare(rules(TieBreak::InOrder), "2AKQ3", "AKQ4J").is_lt());
+        // both one pair, where the pair counts for more than the ace
+        assert!(compare(rules(TieBreak::InOrder), "A2234", "33456").is_gt());
+        assert!(compare(rules(TieBreak::Groups), "A2234", "33456").is_lt());
+        assert!(compare(rules(TieBreak::Groups), "3322A", "2233K").is_gt());
+    }
+}
@@ -0,0 +1,49 @@
+//! Prints a network as a GraphViz graph, optionally highlighting a walk through it.
+//!
+//! Usage: `dot [--walk NODE] [--steps N] [PATH|-]`, for example
+//! `cargo run -p day08 --bin dot -- --walk AAA --steps 20 | dot -Tsvg > network.svg`
+
+use std::{env, path::Path, process};
+
+use common::input::{self, Source};
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut start = None;
+    let mut steps = 0;
+    let mut rest = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--walk" | "-w" => start = Some(args.next().ok_or("--walk expects a node")?),
+            "--steps" | "-n" => {
+                let n = args.next().ok_or("--steps expects a number")?;
+                steps = n.parse().map_err(|e| format!("invalid steps {n}: {e}"))?;
+            }
+            _ => rest.push(arg.clone()),
+        }
+    }
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let source: Source = input::from_args(8, dir, &rest)?;
+    let text = source.read()?;
+    let network = day08::parse(&text).map_err(|e| e.render(&source.to_string(), &text))?;
+
+    let walk = match start {
+        Some(name) => {
+            let start = network
+                .id(name)
+                .ok_or_else(|| format!("unknown node: {name}"))?;
+            network.walk(start).take(steps + 1).collect()
+        }
+        None => Vec::new(),
+    };
+    print!("{}", network.dot(&walk));
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(e) = run(&args) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
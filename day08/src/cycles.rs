@@ -0,0 +1,183 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Every step at which one walker is on an end node.
+///
+/// A walker's state is its node and how far through the instructions it is, so it must
+/// eventually repeat a state and go round the same loop forever. The ends hit before the
+/// loop starts happen once, and those on the loop happen again every `period` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Orbit {
+    /// The ends hit before the loop starts.
+    pub tail: Vec<usize>,
+    /// The step at which the loop starts.
+    pub start: usize,
+    pub period: usize,
+    /// The ends hit the first time round the loop.
+    pub cycle: Vec<usize>,
+}
+
+impl Orbit {
+    /// Walks from `start`, where `next` moves from a node given the index of the instruction
+    /// to follow, until a state repeats. Panics if there are no instructions.
+    pub fn trace<N: Clone + Eq + Hash>(
+        start: N,
+        instructions: usize,
+        next: impl Fn(&N, usize) -> Option<N>,
+        is_end: impl Fn(&N) -> bool,
+    ) -> Option<Self> {
+        assert!(instructions > 0, "a walk needs at least one instruction");
+        let mut seen = HashMap::new();
+        let mut ends = Vec::new();
+        let mut node = start;
+        for step in 0.. {
+            let index = step % instructions;
+            if let Some(first) = seen.insert((node.clone(), index), step) {
+                let (tail, cycle) = ends.iter().partition(|&&end| end < first);
+                return Some(Orbit {
+                    tail,
+                    start: first,
+                    period: step - first,
+                    cycle,
+                });
+            }
+            if is_end(&node) {
+                ends.push(step);
+            }
+            node = next(&node, index)?;
+        }
+        unreachable!("the walk ends when a state repeats")
+    }
+
+    /// Whether the walker is on an end node at `step`.
+    pub fn hits(&self, step: usize) -> bool {
+        if step < self.start {
+            return self.tail.contains(&step);
+        }
+        let offset = (step - self.start) % self.period;
+        self.cycle.contains(&(self.start + offset))
+    }
+
+    /// Every step before `limit` at which the walker is on an end node, in order.
+    fn hits_before(&self, limit: usize) -> impl Iterator<Item = usize> + '_ {
+        let laps = (self.start..limit).step_by(self.period);
+        self.tail
+            .iter()
+            .copied()
+            .chain(laps.flat_map(move |lap| self.cycle.iter().map(move |&c| lap + c - self.start)))
+            .filter(move |&step| step < limit)
+    }
+}
+
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+/// The steps `x` with `x ≡ a₁ (mod m₁)` and `x ≡ a₂ (mod m₂)`, as a residue and modulus, if
+/// there are any. The moduli don't need to be coprime.
+pub fn crt((a1, m1): (u128, u128), (a2, m2): (u128, u128)) -> Option<(u128, u128)> {
+    let (g, p, _) = extended_gcd(m1 as i128, m2 as i128);
+    let diff = a2 as i128 - a1 as i128;
+    if diff % g != 0 {
+        return None;
+    }
+    let modulus = m1 / g as u128 * m2;
+    let step = (m2 as i128) / g;
+    let k = (diff / g % step * p % step + step) % step;
+    Some(((a1 + m1 * k as u128) % modulus, modulus))
+}
+
+/// The first step at which every walker is on an end node at once, if there is one.
+///
+/// Before every walker has reached its loop the steps are checked one hit at a time. After
+/// that, each walker picks one of its loop's ends, and the choices are combined with the
+/// Chinese remainder theorem.
+pub fn first_common(orbits: &[Orbit]) -> Option<usize> {
+    let settled = orbits.iter().map(|o| o.start).max()?;
+    let early = orbits[0]
+        .hits_before(settled)
+        .find(|&step| orbits.iter().all(|o| o.hits(step)));
+    if early.is_some() {
+        return early;
+    }
+
+    let mut congruences = vec![(0u128, 1u128)];
+    for orbit in orbits {
+        congruences = congruences
+            .iter()
+            .flat_map(|&so_far| {
+                orbit.cycle.iter().filter_map(move |&c| {
+                    let residue = (c % orbit.period) as u128;
+                    crt(so_far, (residue, orbit.period as u128))
+                })
+            })
+            .collect();
+    }
+    congruences
+        .into_iter()
+        .map(|(residue, modulus)| {
+            // the smallest step at or after `settled` with this residue
+            let settled = settled as u128;
+            let below = settled % modulus;
+            let first = settled - below + residue;
+            if residue < below {
+                first + modulus
+            } else {
+                first
+            }
+        })
+        .min()
+        .map(|step| usize::try_from(step).expect("steps should fit in a usize"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt((2, 3), (3, 5)), Some((8, 15)));
+        assert_eq!(crt((1, 4), (3, 6)), Some((9, 12)));
+        assert_eq!(crt((0, 4), (1, 6)), None);
+    }
+
+    /// Walks the numbers `0..len` where `n` steps to `jump[n]`.
+    fn orbit(jump: &[usize], ends: &[usize]) -> Orbit {
+        Orbit::trace(0, 1, |&n, _| Some(jump[n]), |n| ends.contains(n)).unwrap()
+    }
+
+    #[test]
+    fn test_orbit() {
+        // 0 -> 1 -> 2 -> 3 -> 1
+        let orbit = orbit(&[1, 2, 3, 1], &[0, 2]);
+        assert_eq!(orbit.tail, [0]);
+        assert_eq!((orbit.start, orbit.period), (1, 3));
+        assert_eq!(orbit.cycle, [2]);
+        assert!(orbit.hits(0) && orbit.hits(5) && !orbit.hits(6));
+    }
+
+    #[test]
+    fn test_first_common() {
+        // a loop of 3 hitting at 2 mod 3, and a loop of 5 hitting at 3 mod 5
+        let three = orbit(&[1, 2, 0], &[2]);
+        let five = orbit(&[1, 2, 3, 4, 0], &[3]);
+        assert_eq!(first_common(&[three.clone(), five]), Some(8));
+
+        // only hit on the way into the loop
+        let tail = orbit(&[1, 2, 3, 1], &[0]);
+        assert_eq!(first_common(&[tail, three.clone()]), None);
+        let early = orbit(&[1, 2, 3, 4, 2], &[2]);
+        assert_eq!(first_common(&[early, three]), Some(2));
+        let odd = orbit(&[1, 0], &[1]);
+        let once = orbit(&[1, 2, 2], &[1]);
+        assert_eq!(first_common(&[once, odd.clone()]), Some(1));
+
+        // loops of 2 and 4 hitting odd and even steps never line up
+        let even = orbit(&[1, 2, 3, 0], &[0, 2]);
+        assert_eq!(first_common(&[odd, even]), None);
+    }
+}
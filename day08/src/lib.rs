@@ -0,0 +1,318 @@
+use std::{collections::HashMap, fmt};
+
+use common::{geometry::Turn, parse, ParseError, Part, Solution};
+
+mod cycles;
+
+pub use cycles::{crt, first_common, Orbit};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Network;
+    type Params = ();
+    type Answer = Steps;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input)
+    }
+}
+
+/// A node in a [`Network`], numbered in the order the nodes are listed.
+pub type NodeId = usize;
+
+/// The network of nodes, with each name interned to a [`NodeId`] and every node's left and
+/// right neighbours resolved when parsing.
+#[derive(Debug)]
+pub struct Network {
+    instructions: Vec<Turn>,
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    edges: Vec<(NodeId, NodeId)>,
+}
+
+impl Network {
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        0..self.names.len()
+    }
+
+    pub fn next(&self, id: NodeId, turn: Turn) -> NodeId {
+        let (left, right) = self.edges[id];
+        match turn {
+            Turn::Left => left,
+            Turn::Right => right,
+        }
+    }
+
+    /// The nodes visited by following the instructions from `start` forever, starting with
+    /// `start` itself.
+    pub fn walk(&self, start: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let turns = self.instructions.iter().cycle();
+        std::iter::once(start).chain(turns.scan(start, |node, &turn| {
+            *node = self.next(*node, turn);
+            Some(*node)
+        }))
+    }
+
+    /// The network in GraphViz's `dot` language, with the edges taken by `walk`, a run of
+    /// nodes such as one from [`Network::walk`], drawn in red and numbered by step.
+    pub fn dot(&self, walk: &[NodeId]) -> String {
+        let mut steps: HashMap<(NodeId, NodeId), Vec<usize>> = HashMap::new();
+        for (step, pair) in walk.windows(2).enumerate() {
+            steps.entry((pair[0], pair[1])).or_default().push(step + 1);
+        }
+        let quote = |id: NodeId| format!("{:?}", self.name(id));
+
+        let mut dot = String::from("digraph network {\n");
+        for id in self.nodes() {
+            let shape = if walk.first() == Some(&id) {
+                "doublecircle"
+            } else {
+                "circle"
+            };
+            dot.push_str(&format!("    {} [shape={shape}];\n", quote(id)));
+        }
+        for id in self.nodes() {
+            let (left, right) = self.edges[id];
+            let edges = if left == right {
+                vec![(left, "LR")]
+            } else {
+                vec![(left, "L"), (right, "R")]
+            };
+            for (to, label) in edges {
+                let mut attributes = format!("label=\"{label}\"");
+                if let Some(steps) = steps.get(&(id, to)) {
+                    let steps: Vec<_> = steps.iter().map(|s| s.to_string()).collect();
+                    attributes = format!(
+                        "label=\"{label} {}\", color=red, penwidth=2",
+                        steps.join(",")
+                    );
+                }
+                dot.push_str(&format!(
+                    "    {} -> {} [{attributes}];\n",
+                    quote(id),
+                    quote(to)
+                ));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// The name of a node and the names of its left and right neighbours.
+fn parse_node(l: &str) -> Result<(&str, &str, &str), ParseError> {
+    let (name, lr) = parse::split_once(l, " = ")?;
+    let pair = lr
+        .strip_prefix('(')
+        .and_then(|lr| lr.strip_suffix(')'))
+        .ok_or_else(|| ParseError::at(l, lr, "expected `(left, right)`"))?;
+    let (left, right) = parse::split_once(pair, ", ").map_err(|e| e.within(l, pair))?;
+    for part in [name, left, right] {
+        if part.is_empty() || part.contains(|c: char| c.is_whitespace() || c == ',') {
+            return Err(ParseError::at(l, part, "invalid node name"));
+        }
+    }
+    Ok((name, left, right))
+}
+
+pub fn parse(input: &str) -> Result<Network, ParseError> {
+    let (instruction_str, map_str) = parse::split_once(input, "\n\n")?;
+    let instructions = parse_instructions(instruction_str)?;
+
+    let nodes = map_str
+        .lines()
+        .map(|l| parse_node(l).map_err(|e| e.within(map_str, l)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.within(input, map_str))?;
+    let mut ids = HashMap::new();
+    for (id, &(name, _, _)) in nodes.iter().enumerate() {
+        if ids.insert(name.to_string(), id).is_some() {
+            return Err(ParseError::at(input, name, "duplicate node"));
+        }
+    }
+    let resolve = |name: &str| {
+        ids.get(name)
+            .copied()
+            .ok_or_else(|| ParseError::at(input, name, "unknown node"))
+    };
+    let edges = nodes
+        .iter()
+        .map(|&(_, left, right)| Ok((resolve(left)?, resolve(right)?)))
+        .collect::<Result<_, ParseError>>()?;
+    Ok(Network {
+        instructions,
+        names: nodes.iter().map(|&(name, _, _)| name.to_string()).collect(),
+        ids,
+        edges,
+    })
+}
+
+/// The first step at which every walker is on an end node, or `None` if that never happens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Steps(pub Option<usize>);
+
+impl fmt::Display for Steps {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(steps) => write!(f, "{steps}"),
+            None => write!(f, "never"),
+        }
+    }
+}
+
+impl Network {
+    /// Where each walker starting at one of `starts` is on a node matching `is_end`.
+    pub fn orbits(
+        &self,
+        starts: impl IntoIterator<Item = NodeId>,
+        is_end: impl Fn(NodeId) -> bool,
+    ) -> Vec<Orbit> {
+        let next = |&node: &NodeId, index: usize| Some(self.next(node, self.instructions[index]));
+        starts
+            .into_iter()
+            .map(|start| {
+                Orbit::trace(start, self.instructions.len(), next, |&n| is_end(n))
+                    .expect("every node is in the network")
+            })
+            .collect()
+    }
+
+    /// The first step at which walkers from every one of `starts` are on end nodes at once.
+    pub fn steps(
+        &self,
+        starts: impl IntoIterator<Item = NodeId>,
+        is_end: impl Fn(NodeId) -> bool,
+    ) -> Steps {
+        Steps(first_common(&self.orbits(starts, is_end)))
+    }
+}
+
+pub fn part1(network: &Network) -> Steps {
+    match (network.id("AAA"), network.id("ZZZ")) {
+        (Some(start), Some(end)) => network.steps([start], |n| n == end),
+        _ => Steps(None),
+    }
+}
+
+pub fn part2(network: &Network) -> Steps {
+    let starts = network.nodes().filter(|&n| network.name(n).ends_with('A'));
+    network.steps(starts, |n| network.name(n).ends_with('Z'))
+}
+
+fn parse_instructions(instructions: &str) -> Result<Vec<Turn>, ParseError> {
+    if instructions.is_empty() {
+        return Err(ParseError::new(
+            "expected at least one instruction",
+            instructions,
+        ));
+    }
+    instructions
+        .char_indices()
+        .map(|(i, c)| Turn::try_from(c).map_err(|e| e.within(instructions, &instructions[i..])))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read input");
+        let result = part1(&parse(&input).unwrap());
+        assert_eq!(result, Steps(Some(2)));
+    }
+
+    #[test]
+    fn test_part1_input2() {
+        let input = fs::read_to_string("test_input2.txt").expect("failed to read input");
+        let result = part1(&parse(&input).unwrap());
+        assert_eq!(result, Steps(Some(6)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input3.txt").expect("failed to read input");
+        let result = part2(&parse(&input).unwrap());
+        assert_eq!(result, Steps(Some(6)));
+    }
+
+    #[test]
+    fn test_no_common_step() {
+        let input = fs::read_to_string("test_input3.txt").expect("failed to read input");
+        let network = parse(&input).unwrap();
+        assert_eq!(part1(&network), Steps(None));
+        assert_eq!(part1(&network).to_string(), "never");
+
+        // 11A ends every 2 steps from step 2, but 33A only ever ends on step 1
+        let input = "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n\
+                     33A = (33Z, 33Z)\n33Z = (33B, 33B)\n33B = (33B, 33B)\n\
+                     XXX = (XXX, XXX)\n";
+        assert_eq!(part2(&parse(input).unwrap()), Steps(None));
+    }
+
+    #[test]
+    fn test_validation() {
+        let network =
+            parse("L\n\nSTART = (LONGER_NAME, START)\nLONGER_NAME = (START, X)\nX = (X, X)\n")
+                .unwrap();
+        let start = network.id("START").unwrap();
+        let walk: Vec<_> = network
+            .walk(start)
+            .take(4)
+            .map(|n| network.name(n))
+            .collect();
+        assert_eq!(walk, ["START", "LONGER_NAME", "START", "LONGER_NAME"]);
+
+        let error = parse("L\n\nAAA = (BBB, AAA)\nBBB = (CCC, AAA)\n").unwrap_err();
+        assert_eq!(error.message(), "unknown node");
+        assert_eq!((error.line(), error.column()), (4, 8));
+        let error = parse("L\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)\n").unwrap_err();
+        assert_eq!(error.message(), "duplicate node");
+        assert!(parse("L\n\nAAA = (A A, AAA)\n").is_err());
+        let error = parse("\n\nAAA = (AAA, AAA)\n").unwrap_err();
+        assert_eq!(error.message(), "expected at least one instruction");
+        assert_eq!((error.line(), error.column()), (1, 1));
+    }
+
+    #[test]
+    fn test_dot() {
+        let input = fs::read_to_string("test_input2.txt").expect("failed to read input");
+        let network = parse(&input).unwrap();
+        let walk: Vec<_> = network.walk(network.id("AAA").unwrap()).take(3).collect();
+        assert_eq!(
+            network.dot(&walk),
+            "digraph network {\n\
+            \x20   \"AAA\" [shape=doublecircle];\n\
+            \x20   \"BBB\" [shape=circle];\n\
+            \x20   \"ZZZ\" [shape=circle];\n\
+            \x20   \"AAA\" -> \"BBB\" [label=\"LR 1\", color=red, penwidth=2];\n\
+            \x20   \"BBB\" -> \"AAA\" [label=\"L 2\", color=red, penwidth=2];\n\
+            \x20   \"BBB\" -> \"ZZZ\" [label=\"R\"];\n\
+            \x20   \"ZZZ\" -> \"ZZZ\" [label=\"LR\"];\n\
+            }\n"
+        );
+    }
+}
@@ -0,0 +1,347 @@
+use std::fmt;
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+use common::{parse, ParseError, Part, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Sequence>;
+    type Params = ();
+    type Answer = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Sequence>, ParseError> {
+    parse::lines(input, parse_line)
+}
+
+pub fn part1(sequences: &[Sequence]) -> i128 {
+    sequences.iter().map(|s| extrapolated(s, 1)).sum()
+}
+
+pub fn part2(sequences: &[Sequence]) -> i128 {
+    sequences.iter().map(|s| extrapolated(s, -1)).sum()
+}
+
+fn extrapolated(sequence: &Sequence, k: i64) -> i128 {
+    sequence
+        .extrapolate(k)
+        .expect("extrapolated value should fit in an i128")
+}
+
+fn parse_line(line: &str) -> Result<Sequence, ParseError> {
+    let values: Vec<i64> = parse::numbers(line)?;
+    if values.is_empty() {
+        return Err(ParseError::new("expected at least one value", line));
+    }
+    Sequence::new(values).ok_or_else(|| ParseError::new("differences overflow", line))
+}
+
+/// A sequence of values taken one step apart from some polynomial, along with its table of
+/// differences.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sequence {
+    /// The values, followed by each row of differences down to the first that is all zeros,
+    /// or to a single value if the values are too few to reach one.
+    rows: Vec<Vec<i64>>,
+}
+
+impl Sequence {
+    /// The sequence of `values`, or `None` if there are none or a difference overflows.
+    pub fn new(values: Vec<i64>) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        let mut rows = vec![values];
+        loop {
+            let row = rows.last().expect("there is always a row");
+            if row.len() == 1 || row.iter().all(|&x| x == 0) {
+                return Some(Self { rows });
+            }
+            let next = row
+                .windows(2)
+                .map(|w| w[1].checked_sub(w[0]))
+                .collect::<Option<Vec<_>>>()?;
+            rows.push(next);
+        }
+    }
+
+    pub fn values(&self) -> &[i64] {
+        &self.rows[0]
+    }
+
+    /// The values followed by each row of differences.
+    pub fn differences(&self) -> &[Vec<i64>] {
+        &self.rows
+    }
+
+    /// The degree of the lowest degree polynomial through every value, counting all zeros as
+    /// degree zero.
+    pub fn degree(&self) -> usize {
+        self.rows
+            .iter()
+            .rposition(|row| row.iter().any(|&x| x != 0))
+            .unwrap_or(0)
+    }
+
+    /// The differences down the leading edge of the table, which are the coefficients of the
+    /// Newton forward difference formula `f(n) = Σ C(n, k)·Δᵏf(0)`.
+    fn leading(&self) -> impl Iterator<Item = i64> + '_ {
+        self.rows.iter().take(self.degree() + 1).map(|row| row[0])
+    }
+
+    /// The value `n` steps after the first, where `n` may be negative or beyond the end, or
+    /// `None` if it overflows an `i128`.
+    pub fn value_at(&self, n: i64) -> Option<i128> {
+        let n = n as i128;
+        let mut binomial: i128 = 1;
+        let mut total: i128 = 0;
+        for (k, difference) in self.leading().enumerate() {
+            if k > 0 {
+                // C(n, k) = C(n, k - 1)·(n - k + 1)/k, which always divides exactly
+                binomial = binomial.checked_mul(n - k as i128 + 1)? / k as i128;
+            }
+            total = total.checked_add(binomial.checked_mul(difference as i128)?)?;
+        }
+        Some(total)
+    }
+
+    /// As `value_at`, but exact for any `n`.
+    pub fn value_at_big(&self, n: &BigInt) -> BigInt {
+        let mut binomial = BigInt::one();
+        let mut total = BigInt::zero();
+        for (k, difference) in self.leading().enumerate() {
+            if k > 0 {
+                binomial = binomial * (n - BigInt::from(k - 1)) / BigInt::from(k);
+            }
+            total += &binomial * difference;
+        }
+        total
+    }
+
+    /// The value `k` steps past the last value, or before the first if `k` is negative.
+    pub fn extrapolate(&self, k: i64) -> Option<i128> {
+        self.value_at(self.position(k)?)
+    }
+
+    /// As `extrapolate`, but exact however far away the value is.
+    pub fn extrapolate_big(&self, k: &BigInt) -> BigInt {
+        let n = if k.is_negative() {
+            k.clone()
+        } else {
+            k + BigInt::from(self.values().len() - 1)
+        };
+        self.value_at_big(&n)
+    }
+
+    fn position(&self, k: i64) -> Option<i64> {
+        if k < 0 {
+            Some(k)
+        } else {
+            k.checked_add(self.values().len() as i64 - 1)
+        }
+    }
+
+    /// The Lagrange polynomial through every value, where the first value is at `x = 0`.
+    pub fn polynomial(&self) -> Polynomial {
+        // expand each Δᵏf(0)·x(x - 1)…(x - k + 1)/k! into powers of x
+        let mut coefficients = vec![BigRational::zero(); self.degree() + 1];
+        let mut falling = vec![BigInt::one()];
+        let mut factorial = BigInt::one();
+        for (k, difference) in self.leading().enumerate() {
+            if k > 0 {
+                factorial *= k;
+                let shift = BigInt::from(k - 1);
+                let mut next = vec![BigInt::zero(); falling.len() + 1];
+                for (i, c) in falling.iter().enumerate() {
+                    next[i + 1] += c;
+                    next[i] -= c * &shift;
+                }
+                falling = next;
+            }
+            for (power, c) in falling.iter().enumerate() {
+                coefficients[power] += BigRational::new(c * difference, factorial.clone());
+            }
+        }
+        Polynomial { coefficients }
+    }
+}
+
+/// A polynomial in `x` with rational coefficients, lowest power first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    pub coefficients: Vec<BigRational>,
+}
+
+impl fmt::Display for Polynomial {
+    /// Highest power first, such as `(1/2)x^2 + (3/2)x + 1`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut terms = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| !c.is_zero())
+            .peekable();
+        if terms.peek().is_none() {
+            return write!(f, "0");
+        }
+        for (i, (power, c)) in terms.enumerate() {
+            let size = c.abs();
+            match (i, c.is_negative()) {
+                (0, true) => write!(f, "-")?,
+                (0, false) => {}
+                (_, true) => write!(f, " - ")?,
+                (_, false) => write!(f, " + ")?,
+            }
+            let x = match power {
+                0 => String::new(),
+                1 => "x".to_string(),
+                _ => format!("x^{power}"),
+            };
+            if size.is_one() && power > 0 {
+                write!(f, "{x}")?;
+            } else if size.is_integer() {
+                write!(f, "{size}{x}")?;
+            } else {
+                write!(f, "({size}){x}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn sequence(values: &[i64]) -> Sequence {
+        Sequence::new(values.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read input file");
+        let result = part1(&parse(&input).unwrap());
+        assert_eq!(result, 114);
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let result = parse_line("0 3 6 9 12 15").unwrap().extrapolate(1);
+        assert_eq!(result, Some(18));
+        let error = parse_line(&format!("{} {}", i64::MIN, i64::MAX)).unwrap_err();
+        assert_eq!(error.message(), "differences overflow");
+        assert_eq!(
+            parse_line("").unwrap_err().message(),
+            "expected at least one value"
+        );
+
+        // the next value is past i64::MAX
+        let sequences = parse(&format!("0 {}", i64::MAX)).unwrap();
+        assert_eq!(part1(&sequences), 2 * i64::MAX as i128);
+    }
+
+    #[test]
+    fn test_next_value() {
+        let result = sequence(&[1, 1, 1, 1]).extrapolate(1);
+        assert_eq!(result, Some(1));
+
+        let result = sequence(&[0, 3, 6, 9, 12, 15]).extrapolate(1);
+        assert_eq!(result, Some(18));
+    }
+
+    #[test]
+    fn test_next_row() {
+        let result = sequence(&[0, 3, 6, 9, 12, 15]).differences()[1].clone();
+        assert_eq!(result, vec![3, 3, 3, 3, 3]);
+
+        let result = sequence(&[1, 1, 1, 1]).differences()[1].clone();
+        assert_eq!(result, vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_prev_value() {
+        let result = sequence(&[1, 1, 1, 1]).extrapolate(-1);
+        assert_eq!(result, Some(1));
+
+        let result = sequence(&[0, 3, 6, 9, 12, 15]).extrapolate(-1);
+        assert_eq!(result, Some(-3));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read input file");
+        let result = part2(&parse(&input).unwrap());
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_degree() {
+        assert_eq!(sequence(&[0, 0, 0]).degree(), 0);
+        assert_eq!(sequence(&[7, 7, 7]).degree(), 0);
+        assert_eq!(sequence(&[0, 3, 6, 9, 12, 15]).degree(), 1);
+        assert_eq!(sequence(&[10, 13, 16, 21, 30, 45]).degree(), 3);
+        // too few values to reach a row of zeros
+        assert_eq!(sequence(&[1, 2, 4]).degree(), 2);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let triangles = sequence(&[1, 3, 6, 10, 15, 21]);
+        assert_eq!(triangles.extrapolate(3), Some(45));
+        assert_eq!(triangles.extrapolate(-3), Some(1));
+        assert_eq!(triangles.value_at(-1), Some(0));
+        assert_eq!(triangles.value_at(1_000_000), Some(500_001_500_001));
+
+        let cubic = sequence(&[10, 13, 16, 21, 30, 45]);
+        let far = i64::MAX;
+        assert_eq!(cubic.extrapolate(far), None);
+        let k = BigInt::from(far);
+        let n = &k + 5;
+        // the fitted cubic is (n³ - 3n² + 11n)/3 + 10
+        let expected = (&n * &n * &n - 3 * &n * &n + 11 * &n) / 3 + 10;
+        assert_eq!(cubic.extrapolate_big(&k), expected);
+        assert_eq!(cubic.extrapolate_big(&BigInt::from(-1)), BigInt::from(5));
+        for k in -10..10 {
+            assert_eq!(
+                cubic.extrapolate(k).map(BigInt::from),
+                Some(cubic.extrapolate_big(&BigInt::from(k)))
+            );
+        }
+    }
+
+    #[test]
+    fn test_polynomial() {
+        let polynomial = |values: &[i64]| sequence(values).polynomial().to_string();
+        assert_eq!(polynomial(&[0, 3, 6, 9, 12, 15]), "3x");
+        assert_eq!(polynomial(&[1, 3, 6, 10, 15, 21]), "(1/2)x^2 + (3/2)x + 1");
+        assert_eq!(
+            polynomial(&[10, 13, 16, 21, 30, 45]),
+            "(1/3)x^3 - x^2 + (11/3)x + 10"
+        );
+        assert_eq!(polynomial(&[0, 0]), "0");
+        assert_eq!(polynomial(&[4, 1, -2]), "-3x + 4");
+        assert_eq!(polynomial(&[0, -1, -4]), "-x^2");
+    }
+}
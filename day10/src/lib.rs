@@ -0,0 +1,426 @@
+use std::{ops::Index, str::FromStr};
+
+use common::{ParseError, Part, Solution};
+use grid::{Direction, Grid, Position};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Map;
+    type Params = ();
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input)
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    tiles: Grid<u8>,
+    starting_position: Position,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let tiles = Grid::parse_with(input, |c| match c {
+            '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' | 'S' => Ok(c as u8),
+            _ => Err(ParseError::new("invalid tile", c)),
+        })?;
+        let starting_position = tiles
+            .position(|&c| c == b'S')
+            .ok_or_else(|| ParseError::new("expected a starting tile `S`", ""))?;
+        Ok(Self {
+            tiles,
+            starting_position,
+        })
+    }
+}
+
+impl Map {
+    fn starting_direction(&self) -> Joint {
+        let connects = |dx, dy, tiles: &[u8]| {
+            self.tiles
+                .offset(self.starting_position, dx, dy)
+                .is_some_and(|p| tiles.contains(&self.tiles[p]))
+        };
+        let mut directions = Vec::new();
+        if connects(0, -1, b"|7F") {
+            directions.push(Direction::North);
+        }
+        if connects(1, 0, b"-7J") {
+            directions.push(Direction::East);
+        }
+        if connects(0, 1, b"|JL") {
+            directions.push(Direction::South);
+        }
+        if connects(-1, 0, b"-LF") {
+            directions.push(Direction::West);
+        }
+        match directions.len() {
+            2 => Joint::new(directions[0], directions[1]),
+            _ => panic!("invalid starting position"),
+        }
+    }
+
+    fn step(&self, position: Position, direction: Direction) -> (Position, Direction) {
+        let new_position = self
+            .tiles
+            .step(position, direction)
+            .expect("pipe leads off the map");
+
+        let new_direction = match self[new_position] {
+            b'|' | b'-' => direction,
+            b'L' => match direction {
+                Direction::South => Direction::East,
+                Direction::West => Direction::North,
+                _ => panic!("invalid direction"),
+            },
+            b'J' => match direction {
+                Direction::South => Direction::West,
+                Direction::East => Direction::North,
+                _ => panic!("invalid direction"),
+            },
+            b'7' => match direction {
+                Direction::North => Direction::West,
+                Direction::East => Direction::South,
+                _ => panic!("invalid direction"),
+            },
+            b'F' => match direction {
+                Direction::North => Direction::East,
+                Direction::West => Direction::South,
+                _ => panic!("invalid direction"),
+            },
+            b'S' => {
+                let joint = self.starting_direction();
+                joint.new_direction
+            }
+            _ => panic!("invalid position"),
+        };
+        (new_position, new_direction)
+    }
+}
+
+impl Index<Position> for Map {
+    type Output = u8;
+
+    fn index(&self, index: Position) -> &Self::Output {
+        &self.tiles[index]
+    }
+}
+
+pub fn part1(map: &Map) -> usize {
+    let mut position = map.starting_position;
+    let joint = map.starting_direction();
+    let mut direction = joint.new_direction;
+
+    let mut steps = 0;
+
+    loop {
+        steps += 1;
+        let (new_position, new_direction) = map.step(position, direction);
+        if map[new_position] == b'S' {
+            break;
+        }
+        position = new_position;
+        direction = new_direction;
+    }
+
+    steps >> 1
+}
+
+pub fn part2(map: &Map) -> usize {
+    let direction_map = generate_directional_map(map);
+    // direction_map.print();
+    count_spins(&direction_map)
+}
+
+fn generate_directional_map(map: &Map) -> DirectionMap {
+    let mut direction_map = DirectionMap::new(map.tiles.height(), map.tiles.width());
+    let mut position = map.starting_position;
+    let joint = map.starting_direction();
+    let mut direction = joint.new_direction;
+
+    let mut up_spin_count = 0;
+    let mut down_spin_count = 0;
+
+    direction_map.joints[position] = Some(joint);
+    if joint.spin() == Some(Spin::Up) {
+        up_spin_count += 1;
+    } else if joint.spin() == Some(Spin::Down) {
+        down_spin_count += 1;
+    }
+
+    loop {
+        let (new_position, new_direction) = map.step(position, direction);
+        if map[new_position] == b'S' {
+            break;
+        }
+        let joint = Joint::new(new_direction, direction);
+        direction_map.joints[new_position] = Some(joint);
+        if joint.spin() == Some(Spin::Up) {
+            up_spin_count += 1;
+        } else if joint.spin() == Some(Spin::Down) {
+            down_spin_count += 1;
+        }
+        position = new_position;
+        direction = new_direction;
+    }
+    if up_spin_count > down_spin_count {
+        direction_map.spin = Some(Spin::Up);
+    } else if down_spin_count > up_spin_count {
+        direction_map.spin = Some(Spin::Down);
+    }
+    direction_map
+}
+
+#[derive(Debug)]
+struct DirectionMap {
+    joints: Grid<Option<Joint>>,
+    spin: Option<Spin>,
+}
+
+impl DirectionMap {
+    fn new(height: usize, width: usize) -> Self {
+        Self {
+            joints: Grid::new(width, height, None),
+            spin: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Spin {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Joint {
+    original_direction: Direction,
+    new_direction: Direction,
+}
+
+impl Joint {
+    fn new(outgoing: Direction, incoming: Direction) -> Self {
+        Self {
+            original_direction: incoming,
+            new_direction: outgoing,
+        }
+    }
+
+    fn spin(&self) -> Option<Spin> {
+        match (self.original_direction, self.new_direction) {
+            (Direction::North, Direction::West) => Some(Spin::Down),
+            (Direction::North, Direction::East) => Some(Spin::Up),
+            (Direction::East, Direction::North) => Some(Spin::Down),
+            (Direction::East, Direction::South) => Some(Spin::Up),
+            (Direction::South, Direction::East) => Some(Spin::Down),
+            (Direction::South, Direction::West) => Some(Spin::Up),
+            (Direction::West, Direction::South) => Some(Spin::Down),
+            (Direction::West, Direction::North) => Some(Spin::Up),
+            _ => None,
+        }
+    }
+}
+
+fn count_spins(map: &DirectionMap) -> usize {
+    let mut count = 0;
+    for (position, joint) in map.joints.iter() {
+        if joint.is_none() {
+            let spin = check_spin(map, position);
+            if Some(spin) == Some(map.spin) {
+                count += 1;
+            }
+        };
+    }
+    count
+}
+
+fn check_spin(map: &DirectionMap, position: Position) -> Option<Spin> {
+    let north = check_north(map, position)?;
+    let _ = check_south(map, position)?;
+    let _ = check_east(map, position)?;
+    let _ = check_west(map, position)?;
+
+    match north {
+        Joint {
+            new_direction: Direction::North,
+            original_direction: Direction::West,
+        } => Some(Spin::Down),
+        Joint {
+            new_direction: Direction::North,
+            original_direction: Direction::East,
+        } => Some(Spin::Up),
+        Joint {
+            new_direction: Direction::East,
+            original_direction: _,
+        } => Some(Spin::Up),
+        Joint {
+            new_direction: Direction::West,
+            original_direction: _,
+        } => Some(Spin::Down),
+        _ => panic!("invalid direction"),
+    }
+}
+
+fn check_north(map: &DirectionMap, position: Position) -> Option<Joint> {
+    if position.y == 0 && map.joints[position].is_none() {
+        return None;
+    }
+
+    match map.joints[position] {
+        None => check_north(
+            map,
+            Position {
+                x: position.x,
+                y: position.y - 1,
+            },
+        ),
+        Some(direction) => Some(direction),
+    }
+}
+
+fn check_south(map: &DirectionMap, position: Position) -> Option<Joint> {
+    if position.y == map.joints.height() - 1 && map.joints[position].is_none() {
+        return None;
+    }
+
+    match map.joints[position] {
+        None => check_south(
+            map,
+            Position {
+                x: position.x,
+                y: position.y + 1,
+            },
+        ),
+        Some(direction) => Some(direction),
+    }
+}
+
+fn check_east(map: &DirectionMap, position: Position) -> Option<Joint> {
+    if position.x == map.joints.width() - 1 && map.joints[position].is_none() {
+        return None;
+    }
+
+    match map.joints[position] {
+        None => check_east(
+            map,
+            Position {
+                x: position.x + 1,
+                y: position.y,
+            },
+        ),
+        Some(direction) => Some(direction),
+    }
+}
+
+fn check_west(map: &DirectionMap, position: Position) -> Option<Joint> {
+    if position.x == 0 && map.joints[position].is_none() {
+        return None;
+    }
+
+    match map.joints[position] {
+        None => check_west(
+            map,
+            Position {
+                x: position.x - 1,
+                y: position.y,
+            },
+        ),
+        Some(direction) => Some(direction),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(part1(&input.parse::<Map>().unwrap()), 8);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input2.txt").expect("failed to read test input file");
+        assert_eq!(part2(&input.parse::<Map>().unwrap()), 4);
+    }
+
+    #[test]
+    fn test_is_inside() {
+        let input = fs::read_to_string("test_input2.txt").expect("failed to read test input file");
+        let map = input.parse::<Map>().unwrap();
+        let direction_map = generate_directional_map(&map);
+        assert_eq!(
+            check_spin(&direction_map, Position { x: 2, y: 6 }),
+            Some(Spin::Up)
+        );
+        assert_eq!(
+            check_spin(&direction_map, Position { x: 3, y: 6 }),
+            Some(Spin::Up)
+        );
+        assert_eq!(
+            check_spin(&direction_map, Position { x: 3, y: 4 }),
+            Some(Spin::Down)
+        );
+        assert_eq!(check_spin(&direction_map, Position { x: 1, y: 8 }), None);
+    }
+
+    #[test]
+    fn test_part2_2() {
+        let input = fs::read_to_string("test_input3.txt").expect("failed to read test input file");
+        assert_eq!(part2(&input.parse::<Map>().unwrap()), 8);
+    }
+
+    #[test]
+    fn test_is_inside_2() {
+        let input = fs::read_to_string("test_input3.txt").expect("failed to read test input file");
+        let map = input.parse::<Map>().unwrap();
+        let direction_map = generate_directional_map(&map);
+        assert_eq!(
+            check_spin(&direction_map, Position { x: 3, y: 2 }),
+            Some(Spin::Up)
+        );
+        assert_eq!(
+            check_spin(&direction_map, Position { x: 7, y: 4 }),
+            Some(Spin::Down)
+        );
+    }
+
+    #[test]
+    fn test_check_north() {
+        let input = fs::read_to_string("test_input2.txt").expect("failed to read test input file");
+        let map = input.parse::<Map>().unwrap();
+        let direction_map = generate_directional_map(&map);
+        assert_eq!(
+            check_north(&direction_map, Position { x: 3, y: 6 }),
+            Some(Joint {
+                new_direction: Direction::East,
+                original_direction: Direction::East
+            })
+        );
+    }
+
+    #[test]
+    fn test_part2_3() {
+        let input = fs::read_to_string("test_input4.txt").expect("failed to read test input file");
+        assert_eq!(part2(&input.parse::<Map>().unwrap()), 10);
+    }
+}
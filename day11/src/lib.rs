@@ -0,0 +1,144 @@
+use common::{geometry::Point, parse, ParseError, Part, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Point>;
+    type Params = i64;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        map_galaxies(input)
+    }
+
+    fn params(part: Part) -> Self::Params {
+        match part {
+            Part::One => 2,
+            Part::Two => 1000000,
+        }
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+        solve(input, *params)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+        solve(input, *params)
+    }
+}
+
+pub fn solve(galaxies: &[Point], expand_factor: i64) -> i64 {
+    let mut galaxies = galaxies.to_vec();
+    let (empty_rows, empty_columns) = find_empty_rows_and_columns(&galaxies);
+    expand_universe(&mut galaxies, &empty_rows, &empty_columns, expand_factor);
+
+    let mut sum = 0;
+    for i in 0..galaxies.len() {
+        for j in i + 1..galaxies.len() {
+            sum += galaxies[i].manhattan(galaxies[j]);
+        }
+    }
+    sum
+}
+
+pub fn map_galaxies(input: &str) -> Result<Vec<Point>, ParseError> {
+    let map = parse::grid(input, |c| match c {
+        '.' => Ok(false),
+        '#' => Ok(true),
+        _ => Err(ParseError::new("invalid character", c)),
+    })?;
+    let mut galaxies = Vec::new();
+    for (y, row) in map.iter().enumerate() {
+        for (x, &galaxy) in row.iter().enumerate() {
+            if galaxy {
+                galaxies.push(Point::new(x as i64, y as i64));
+            }
+        }
+    }
+    Ok(galaxies)
+}
+
+fn find_empty_rows_and_columns(galaxies: &[Point]) -> (Vec<i64>, Vec<i64>) {
+    let mut empty_rows = Vec::new();
+    let mut empty_columns = Vec::new();
+    for y in 0..galaxies.len() {
+        if !galaxies.iter().any(|g| g.y == y as i64) {
+            empty_rows.push(y as i64);
+        }
+    }
+    for x in 0..galaxies.len() {
+        if !galaxies.iter().any(|g| g.x == x as i64) {
+            empty_columns.push(x as i64);
+        }
+    }
+    empty_rows.reverse();
+    empty_columns.reverse();
+    (empty_rows, empty_columns)
+}
+
+fn expand_universe(
+    galaxies: &mut [Point],
+    empty_rows: &[i64],
+    empty_columns: &[i64],
+    expand_factor: i64,
+) {
+    for galaxy in galaxies.iter_mut() {
+        for (i, &row) in empty_rows.iter().enumerate() {
+            if galaxy.y > row {
+                galaxy.y += (empty_rows.len() as i64 - i as i64) * (expand_factor - 1);
+                break;
+            }
+        }
+        for (i, &column) in empty_columns.iter().enumerate() {
+            if galaxy.x > column {
+                galaxy.x += (empty_columns.len() as i64 - i as i64) * (expand_factor - 1);
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read input file");
+        let result = solve(&map_galaxies(&input).unwrap(), 2);
+        assert_eq!(result, 374);
+    }
+
+    #[test]
+    fn test_find_empty_rows_and_columns() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read input file");
+        let galaxies = map_galaxies(&input).unwrap();
+        let (empty_rows, empty_columns) = find_empty_rows_and_columns(&galaxies);
+        assert_eq!(empty_rows, vec![7, 3]);
+        assert_eq!(empty_columns, vec![8, 5, 2]);
+    }
+
+    #[test]
+    fn test_expand_universe() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read input file");
+        let mut galaxies = map_galaxies(&input).unwrap();
+        let (empty_rows, empty_columns) = find_empty_rows_and_columns(&galaxies);
+        assert_eq!(galaxies[0], Point::new(3, 0));
+        expand_universe(&mut galaxies, &empty_rows, &empty_columns, 2);
+        assert_eq!(galaxies[0], Point::new(4, 0));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read input file");
+        let result = solve(&map_galaxies(&input).unwrap(), 2);
+        assert_eq!(result, 374);
+        let result = solve(&map_galaxies(&input).unwrap(), 10);
+        assert_eq!(result, 1030);
+        let result = solve(&map_galaxies(&input).unwrap(), 100);
+        assert_eq!(result, 8410);
+    }
+}
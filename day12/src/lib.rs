@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+use common::{parse, ParseError, Part, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Row>;
+    type Params = ();
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl TryFrom<char> for Spring {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Spring::Operational),
+            '#' => Ok(Spring::Damaged),
+            '?' => Ok(Spring::Unknown),
+            _ => Err(ParseError::new("invalid spring", c)),
+        }
+    }
+}
+
+pub type Row = (Vec<Spring>, Vec<usize>);
+
+pub fn parse(input: &str) -> Result<Vec<Row>, ParseError> {
+    parse::lines(input, parse_line)
+}
+
+pub fn part1(rows: &[Row]) -> usize {
+    rows.iter()
+        .map(|(springs, criteria)| {
+            let mut cache = HashMap::new();
+            number_arrangements(springs, criteria, &mut cache)
+        })
+        .sum()
+}
+
+pub fn part2(rows: &[Row]) -> usize {
+    rows.iter()
+        .map(|(springs, criteria)| {
+            let (springs, criteria) = unfold(springs, criteria);
+            let mut cache = HashMap::new();
+            number_arrangements(&springs, &criteria, &mut cache)
+        })
+        .sum()
+}
+
+fn parse_line(l: &str) -> Result<Row, ParseError> {
+    let (springs_str, criteria_str) = parse::split_once(l, " ")?;
+    let criteria = criteria_str
+        .split(',')
+        .map(|s| parse::number(s).map_err(|e| e.within(l, s)))
+        .collect::<Result<_, _>>()?;
+
+    let springs = springs_str
+        .char_indices()
+        .map(|(i, c)| Spring::try_from(c).map_err(|e| e.within(l, &l[i..])))
+        .collect::<Result<_, _>>()?;
+    Ok((springs, criteria))
+}
+
+fn unfold(springs: &[Spring], criteria: &[usize]) -> Row {
+    let criteria: Vec<_> = criteria
+        .iter()
+        .copied()
+        .cycle()
+        .take(criteria.len() * 5)
+        .collect();
+
+    let springs: Vec<_> = springs
+        .iter()
+        .copied()
+        .chain([Spring::Unknown])
+        .cycle()
+        .take(springs.len() * 5 + 4)
+        .collect();
+    (springs, criteria)
+}
+
+fn number_arrangements(
+    springs: &[Spring],
+    criteria: &[usize],
+    cache: &mut HashMap<(usize, usize), usize>,
+) -> usize {
+    if criteria.is_empty() {
+        if springs.contains(&Spring::Damaged) {
+            return 0;
+        }
+        return 1;
+    }
+
+    if springs.len() < criteria.iter().sum::<usize>() + criteria.len() - 1 {
+        return 0;
+    }
+
+    if let Some(&c) = cache.get(&(springs.len(), criteria.len())) {
+        return c;
+    }
+
+    let mut count = 0;
+    if springs[0] != Spring::Damaged {
+        count += number_arrangements(&springs[1..], criteria, cache);
+    }
+    let criteria_size = criteria[0];
+    if springs.len() == criteria_size && !springs.contains(&Spring::Operational) {
+        count += 1;
+    } else if !springs[..criteria_size].contains(&Spring::Operational)
+        && springs[criteria_size] != Spring::Damaged
+    {
+        count += number_arrangements(&springs[criteria_size + 1..], &criteria[1..], cache);
+    }
+
+    cache.insert((springs.len(), criteria.len()), count);
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(part1(&parse(&input).unwrap()), 21);
+    }
+
+    #[test]
+    fn test_number_arrangements() {
+        let row = vec![
+            Spring::Unknown,
+            Spring::Unknown,
+            Spring::Unknown,
+            Spring::Operational,
+            Spring::Damaged,
+            Spring::Damaged,
+            Spring::Damaged,
+        ];
+        let criteria = vec![1, 1, 3];
+        let mut cache = HashMap::new();
+        let result = number_arrangements(&row, &criteria, &mut cache);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_unfolded_valid() {
+        let (springs, criteria) = parse_line("???.### 1,1,3").unwrap();
+        let (springs, criteria) = unfold(&springs, &criteria);
+        let mut cache = HashMap::new();
+        let result = number_arrangements(&springs, &criteria, &mut cache);
+        assert_eq!(result, 1);
+
+        let (springs, criteria) = parse_line(".??..??...?##. 1,1,3").unwrap();
+        let (springs, criteria) = unfold(&springs, &criteria);
+        let mut cache = HashMap::new();
+        let result = number_arrangements(&springs, &criteria, &mut cache);
+        assert_eq!(result, 16384);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(part2(&parse(&input).unwrap()), 525152);
+    }
+}
@@ -0,0 +1,162 @@
+#![allow(dead_code)]
+
+use std::{cmp, str::FromStr};
+
+use common::{ParseError, Part, Solution};
+use grid::{Cell, Grid};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Pattern>;
+    type Params = usize;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    /// The number of smudges that must be fixed for a line to be a reflection.
+    fn params(part: Part) -> Self::Params {
+        match part {
+            Part::One => 0,
+            Part::Two => 1,
+        }
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+        solve(input, *params)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+        solve(input, *params)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    input
+        .split("\n\n")
+        .map(|p| Pattern::from_str(p).map_err(|e| e.within(input, p)))
+        .collect()
+}
+
+pub fn solve(patterns: &[Pattern], target: usize) -> usize {
+    patterns.iter().map(|p| p.reflection_value(target)).sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Element {
+    Ash,
+    Rock,
+}
+
+impl Cell for Element {
+    fn from_char(c: char) -> Result<Self, ParseError> {
+        match c {
+            '.' => Ok(Element::Ash),
+            '#' => Ok(Element::Rock),
+            _ => Err(ParseError::new("invalid element", c)),
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Element::Ash => '.',
+            Element::Rock => '#',
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Pattern {
+    map: Grid<Element>,
+}
+
+impl FromStr for Pattern {
+    type Err = ParseError;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            map: pattern.parse()?,
+        })
+    }
+}
+
+impl Pattern {
+    fn reflection_value(&self, target: usize) -> usize {
+        let vertical = self.vertical_reflection(target);
+        let horizontal = self.horizontal_reflection(target);
+        if let Some(v) = vertical {
+            return v;
+        }
+        if let Some(h) = horizontal {
+            return 100 * h;
+        }
+        panic!("no reflection found");
+    }
+
+    fn vertical_reflection(&self, target: usize) -> Option<usize> {
+        reflection(&self.map.transpose(), target)
+    }
+
+    fn horizontal_reflection(&self, target: usize) -> Option<usize> {
+        reflection(&self.map, target)
+    }
+}
+
+/// The number of rows above the line between rows that mirrors the grid with exactly
+/// `target` mismatched elements.
+fn reflection(map: &Grid<Element>, target: usize) -> Option<usize> {
+    for y in 0..(map.height() - 1) {
+        let height = cmp::min(y + 1, map.height() - y - 1);
+        let result: usize = (0..height)
+            .map(|i| {
+                let up = map.row(y - i);
+                let down = map.row(y + i + 1);
+                Iterator::zip(up.iter(), down)
+                    .filter(|(u, d)| u != d)
+                    .count()
+            })
+            .sum();
+        if result == target {
+            return Some(y + 1);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to load input file");
+        let result = solve(&parse(&input).unwrap(), 0);
+        assert_eq!(result, 405);
+    }
+
+    #[test]
+    fn test_reflection() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to load input file");
+        let (v, h) = input.split_once("\n\n").expect("should be 2 patterns");
+
+        let vertical = v.parse::<Pattern>().unwrap();
+        assert_eq!(vertical.horizontal_reflection(0), None);
+        assert_eq!(vertical.vertical_reflection(0), Some(5));
+
+        let horizontal = h.parse::<Pattern>().unwrap();
+        assert_eq!(horizontal.horizontal_reflection(0), Some(4));
+        assert_eq!(horizontal.vertical_reflection(0), None);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to load input file");
+        let result = solve(&parse(&input).unwrap(), 1);
+        assert_eq!(result, 400);
+    }
+}
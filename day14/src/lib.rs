@@ -0,0 +1,231 @@
+use std::str::FromStr;
+
+use common::{ParseError, Part, Solution};
+use grid::{Cell, Grid, Position};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Platform;
+    type Params = ();
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+enum Rock {
+    Round,
+    Cube,
+    Empty,
+}
+
+impl Cell for Rock {
+    fn from_char(c: char) -> Result<Self, ParseError> {
+        match c {
+            'O' => Ok(Rock::Round),
+            '#' => Ok(Rock::Cube),
+            '.' => Ok(Rock::Empty),
+            _ => Err(ParseError::new("invalid rock", c)),
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Rock::Round => 'O',
+            Rock::Cube => '#',
+            Rock::Empty => '.',
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Platform {
+    rocks: Grid<Rock>,
+}
+
+impl FromStr for Platform {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            rocks: input.parse()?,
+        })
+    }
+}
+
+impl Platform {
+    fn score(&self) -> usize {
+        let height = self.rocks.height();
+        self.rocks
+            .rows()
+            .enumerate()
+            .map(|(i, l)| l.iter().filter(|&&r| r == Rock::Round).count() * (height - i))
+            .sum()
+    }
+
+    fn tilt_north(&mut self) {
+        for x in 0..self.rocks.width() {
+            // the highest row a round rock in this column can roll to
+            let mut free = 0;
+            for y in 0..self.rocks.height() {
+                let position = Position::new(x, y);
+                match self.rocks[position] {
+                    Rock::Cube => free = y + 1,
+                    Rock::Round => {
+                        self.rocks[position] = Rock::Empty;
+                        self.rocks[Position::new(x, free)] = Rock::Round;
+                        free += 1;
+                    }
+                    Rock::Empty => (),
+                }
+            }
+        }
+    }
+
+    /// Tilts north, west, south and then east, by tilting north and turning the platform
+    /// clockwise so the next side is at the top.
+    fn spin(&mut self) {
+        for _ in 0..4 {
+            self.tilt_north();
+            self.rocks = self.rocks.rotate_clockwise();
+        }
+    }
+
+    fn cycle(&mut self, count: usize) {
+        let mut cache: Vec<Grid<Rock>> = Vec::new();
+
+        for i in 0..count {
+            if let Some(index) = cache.iter().position(|c| c == &self.rocks) {
+                let cycle_length = i - index;
+                let final_index = index + (count - index) % cycle_length;
+
+                self.rocks = cache[final_index].clone();
+                return;
+            }
+            cache.push(self.rocks.clone());
+
+            self.spin();
+        }
+    }
+}
+
+pub fn part1(platform: &Platform) -> usize {
+    let mut platform = platform.clone();
+    platform.tilt_north();
+    platform.score()
+}
+
+pub fn part2(platform: &Platform) -> usize {
+    let mut platform = platform.clone();
+    platform.cycle(1_000_000_000);
+    platform.score()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(part1(&input.parse::<Platform>().unwrap()), 136);
+    }
+
+    #[test]
+    fn test_tilt_north() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        let expected_input = r#"OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#...."#;
+        let mut platform = input.parse::<Platform>().unwrap();
+        platform.tilt_north();
+        let expected = expected_input.parse::<Platform>().unwrap();
+        assert_eq!(platform, expected);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        let expected_input = r#".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#...."#;
+        let mut platform = input.parse::<Platform>().unwrap();
+        platform.cycle(1);
+        let expected = expected_input.parse::<Platform>().unwrap();
+        assert_eq!(platform, expected);
+    }
+
+    #[test]
+    fn test_cycle_2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        let expected_input = r#".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#..OO###..
+#.OOO#...O"#;
+        let mut platform = input.parse::<Platform>().unwrap();
+        platform.cycle(2);
+        let expected = expected_input.parse::<Platform>().unwrap();
+        assert_eq!(platform, expected);
+    }
+
+    #[test]
+    fn test_cycle_3() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        let expected_input = r#".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O"#;
+        let mut platform = input.parse::<Platform>().unwrap();
+        platform.cycle(3);
+        let expected = expected_input.parse::<Platform>().unwrap();
+        assert_eq!(platform, expected);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(part2(&input.parse::<Platform>().unwrap()), 64);
+    }
+}
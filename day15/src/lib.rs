@@ -0,0 +1,144 @@
+use common::{parse, ParseError, Part, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Vec<String>;
+    type Params = ();
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input)
+    }
+}
+
+/// Splits the initialization sequence into steps, checking that each is `name-` or
+/// `name=N` while keeping the raw text for hashing.
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    input
+        .trim()
+        .split(',')
+        .map(|step| {
+            if let Some((_, focal_len)) = step.split_once('=') {
+                parse::number::<usize>(focal_len).map_err(|e| e.within(input, focal_len))?;
+            } else if !step.ends_with('-') {
+                return Err(ParseError::at(input, step, "expected `=` or `-`"));
+            }
+            Ok(step.to_string())
+        })
+        .collect()
+}
+
+pub fn part1(steps: &[String]) -> usize {
+    steps.iter().map(|s| calculate_hash(s.as_bytes())).sum()
+}
+
+fn calculate_hash(input: &[u8]) -> usize {
+    let mut current_value: usize = 0;
+    for &c in input {
+        current_value += c as usize;
+        current_value *= 17;
+        current_value %= 256;
+    }
+    current_value
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Lens {
+    name: String,
+    focal_len: usize,
+}
+
+impl Lens {
+    fn new(name: &str, focal_len: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            focal_len,
+        }
+    }
+}
+
+pub fn part2(steps: &[String]) -> usize {
+    let mut boxes: Vec<Option<Vec<Lens>>> = vec![None; 256];
+    for step in steps {
+        if step.contains("-") {
+            let (name, _) = step.split_once("-").expect("failed to split step");
+            for lenses in boxes.iter_mut().flatten() {
+                lenses.retain(|l| l.name != name);
+            }
+        } else {
+            let (name, focal_len_str) = step.split_once("=").expect("failed to split step");
+            let focal_len = focal_len_str
+                .parse::<usize>()
+                .expect("failed to parse focal length for =");
+            let lens = Lens::new(name, focal_len);
+            let hash = calculate_hash(name.as_bytes());
+            if let Some(ref mut lenses) = &mut boxes[hash] {
+                if let Some(existing_lens_index) = lenses.iter().position(|l| l.name == lens.name) {
+                    lenses[existing_lens_index] = lens;
+                } else {
+                    lenses.push(lens);
+                }
+            } else {
+                boxes[hash] = Some(vec![lens]);
+            }
+        }
+    }
+
+    boxes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, b)| {
+            b.as_ref().map(|lenses| {
+                lenses
+                    .iter()
+                    .enumerate()
+                    .map(|(j, l)| (i + 1) * (j + 1) * l.focal_len)
+                    .sum::<usize>()
+            })
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        let result = part1(&parse(&input).unwrap());
+        assert_eq!(result, 1320);
+    }
+
+    #[test]
+    fn test_calculate_hash() {
+        let input = "rn=1";
+        let result = calculate_hash(input.as_bytes());
+        assert_eq!(result, 30);
+
+        let input = "cm-";
+        let result = calculate_hash(input.as_bytes());
+        assert_eq!(result, 253);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        let result = part2(&parse(&input).unwrap());
+        assert_eq!(result, 145);
+    }
+}
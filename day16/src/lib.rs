@@ -0,0 +1,195 @@
+use std::str::FromStr;
+
+use common::{
+    geometry::{Direction, Point},
+    ParseError, Part, Solution,
+};
+use grid::{Cell, Grid, Position};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Map;
+    type Params = ();
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Beam {
+    position: Point,
+    direction: Direction,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Tile {
+    Empty,
+    BackMirror,
+    ForwardMirror,
+    HorizontalSplitter,
+    VerticalSplitter,
+}
+
+impl Cell for Tile {
+    fn from_char(c: char) -> Result<Self, ParseError> {
+        match c {
+            '.' => Ok(Tile::Empty),
+            '\\' => Ok(Tile::BackMirror),
+            '/' => Ok(Tile::ForwardMirror),
+            '-' => Ok(Tile::HorizontalSplitter),
+            '|' => Ok(Tile::VerticalSplitter),
+            _ => Err(ParseError::new("invalid tile", c)),
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::BackMirror => '\\',
+            Tile::ForwardMirror => '/',
+            Tile::HorizontalSplitter => '-',
+            Tile::VerticalSplitter => '|',
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    tiles: Grid<Tile>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            tiles: input.parse()?,
+        })
+    }
+}
+
+impl Map {
+    fn traverse(&self, energized_map: &mut EnergizedMap, beam: Beam) {
+        // don't track the initial beam, which starts just outside the map
+        if let Some(beams) = cell(beam.position).and_then(|c| energized_map.get_mut(c)) {
+            if beams.contains(&beam) {
+                return;
+            }
+            beams.push(beam);
+        }
+        let next_position = beam.position.step(beam.direction);
+        let Some(tile) = cell(next_position).and_then(|c| self.tiles.get(c)) else {
+            return;
+        };
+        let next_directions = match tile {
+            Tile::BackMirror if beam.direction.is_horizontal() => vec![beam.direction.turn_right()],
+            Tile::BackMirror => vec![beam.direction.turn_left()],
+            Tile::ForwardMirror if beam.direction.is_vertical() => {
+                vec![beam.direction.turn_right()]
+            }
+            Tile::ForwardMirror => vec![beam.direction.turn_left()],
+            Tile::HorizontalSplitter if beam.direction.is_vertical() => {
+                vec![beam.direction.turn_left(), beam.direction.turn_right()]
+            }
+            Tile::VerticalSplitter if beam.direction.is_horizontal() => {
+                vec![beam.direction.turn_left(), beam.direction.turn_right()]
+            }
+            Tile::Empty | Tile::HorizontalSplitter | Tile::VerticalSplitter => vec![beam.direction],
+        };
+        for next_direction in next_directions {
+            let next_beam = Beam {
+                position: next_position,
+                direction: next_direction,
+            };
+            self.traverse(energized_map, next_beam);
+        }
+    }
+}
+
+/// The grid cell at `point`, unless it is off the top or left edge.
+fn cell(point: Point) -> Option<Position> {
+    Position::try_from(point).ok()
+}
+
+/// The beams that have passed through each tile.
+type EnergizedMap = Grid<Vec<Beam>>;
+
+fn find_energized_tile_count(map: &Map, start_beam: Beam) -> usize {
+    let mut energized_map = EnergizedMap::new(map.tiles.width(), map.tiles.height(), Vec::new());
+    map.traverse(&mut energized_map, start_beam);
+    energized_map.iter().filter(|(_, b)| !b.is_empty()).count()
+}
+
+pub fn part1(map: &Map) -> usize {
+    find_energized_tile_count(
+        map,
+        Beam {
+            position: Point::new(-1, 0),
+            direction: Direction::East,
+        },
+    )
+}
+
+pub fn part2(map: &Map) -> usize {
+    let width = map.tiles.width() as i64;
+    let height = map.tiles.height() as i64;
+    let mut starts = Vec::new();
+    for x in 0..width {
+        starts.push(Beam {
+            position: Point::new(x, -1),
+            direction: Direction::South,
+        });
+        starts.push(Beam {
+            position: Point::new(x, height),
+            direction: Direction::North,
+        });
+    }
+    for y in 0..height {
+        starts.push(Beam {
+            position: Point::new(-1, y),
+            direction: Direction::East,
+        });
+        starts.push(Beam {
+            position: Point::new(width, y),
+            direction: Direction::West,
+        });
+    }
+    starts
+        .iter()
+        .map(|&b| find_energized_tile_count(map, b))
+        .max()
+        .expect("failed to find max")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(part1(&input.parse::<Map>().unwrap()), 46);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(part2(&input.parse::<Map>().unwrap()), 51);
+    }
+}
@@ -0,0 +1,195 @@
+#![allow(dead_code)]
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    str::FromStr,
+};
+
+use common::{ParseError, Part, Solution};
+use grid::{Direction, Grid, Position};
+
+/// How many blocks a crucible must, and may at most, move before turning.
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    pub min: i32,
+    pub max: i32,
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Map;
+    type Params = Limits;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn params(part: Part) -> Self::Params {
+        match part {
+            Part::One => Limits { min: 1, max: 3 },
+            Part::Two => Limits { min: 4, max: 10 },
+        }
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+        solve(input, params.min, params.max)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+        solve(input, params.min, params.max)
+    }
+}
+
+/// The heat lost entering each block.
+#[derive(Debug)]
+pub struct Map {
+    blocks: Grid<usize>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let blocks = Grid::parse_with(input, |c| {
+            c.to_digit(10)
+                .map(|d| d as usize)
+                .ok_or_else(|| ParseError::new("expected a digit", c))
+        })?;
+        Ok(Self { blocks })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct State {
+    position: Position,
+    direction: Direction,
+    same_direction_count: i32,
+}
+
+impl State {
+    fn new(position: Position, direction: Direction, same_direction_count: i32) -> Self {
+        Self {
+            position,
+            direction,
+            same_direction_count,
+        }
+    }
+}
+
+pub fn solve(map: &Map, min_same_direction_count: i32, max_same_direction_count: i32) -> usize {
+    let start_position = Position { x: 0, y: 0 };
+    let target_position = Position {
+        x: map.blocks.width() - 1,
+        y: map.blocks.height() - 1,
+    };
+    dijkstra(
+        map,
+        start_position,
+        target_position,
+        min_same_direction_count,
+        max_same_direction_count,
+    )
+}
+
+fn dijkstra(
+    map: &Map,
+    start_position: Position,
+    target_position: Position,
+    min_same_direction_count: i32,
+    max_same_direction_count: i32,
+) -> usize {
+    let mut distances = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    let start_state_right = State::new(start_position, Direction::East, 0);
+    let start_state_down = State::new(start_position, Direction::South, 0);
+    let mut seen = HashSet::new();
+    distances.insert(start_state_right, 0);
+    distances.insert(start_state_down, 0);
+    queue.push(Reverse((0, start_state_right)));
+    queue.push(Reverse((0, start_state_down)));
+    while let Some(Reverse((current_distance, state))) = queue.pop() {
+        seen.insert(state);
+        let neighbours = get_neighbours(
+            map,
+            state,
+            min_same_direction_count,
+            max_same_direction_count,
+        );
+        for neighbour in neighbours {
+            if seen.contains(&neighbour) {
+                continue;
+            }
+            let distance = current_distance + map.blocks[neighbour.position];
+            let neighbour_distance = distances.get(&neighbour).unwrap_or(&usize::MAX);
+            if distance < *neighbour_distance {
+                if neighbour.position == target_position {
+                    return distance;
+                }
+                distances.insert(neighbour, distance);
+                queue.push(Reverse((distance, neighbour)));
+            }
+        }
+    }
+    usize::MAX
+}
+
+fn get_neighbours(
+    map: &Map,
+    state: State,
+    min_same_direction_count: i32,
+    max_same_direction_count: i32,
+) -> Vec<State> {
+    let mut neighbours = Vec::new();
+    if state.same_direction_count < max_same_direction_count {
+        if let Some(position) = map.blocks.step(state.position, state.direction) {
+            neighbours.push(State::new(
+                position,
+                state.direction,
+                state.same_direction_count + 1,
+            ));
+        }
+    }
+    if state.same_direction_count >= min_same_direction_count {
+        for direction in [state.direction.turn_left(), state.direction.turn_right()] {
+            // only turn if there's room to go the minimum distance before the edge
+            let room = state
+                .position
+                .step_by(direction, min_same_direction_count as usize)
+                .is_some_and(|p| map.blocks.contains(p));
+            if let Some(position) = map.blocks.step(state.position, direction).filter(|_| room) {
+                neighbours.push(State::new(position, direction, 1));
+            }
+        }
+    }
+
+    neighbours
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(solve(&input.parse::<Map>().unwrap(), 1, 3), 102);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(solve(&input.parse::<Map>().unwrap(), 4, 10), 94);
+    }
+
+    #[test]
+    fn test_part2_2() {
+        let input = fs::read_to_string("test_input2.txt").expect("failed to read test input file");
+        assert_eq!(solve(&input.parse::<Map>().unwrap(), 4, 10), 71);
+    }
+}
@@ -0,0 +1,174 @@
+#![allow(dead_code)]
+
+use std::str::FromStr;
+
+use common::{
+    geometry::{Direction, Point},
+    parse, ParseError, Part, Solution,
+};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = DigPlan;
+    type Params = ();
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input)
+    }
+}
+
+#[derive(Debug)]
+struct Edge {
+    direction: Direction,
+    length: usize,
+}
+
+impl Edge {
+    fn new(direction: Direction, length: usize) -> Self {
+        Self { direction, length }
+    }
+}
+
+impl FromStr for Edge {
+    type Err = ParseError;
+
+    fn from_str(l: &str) -> Result<Self, Self::Err> {
+        let (direction_str, s) = parse::split_once(l, " ")?;
+        let direction = single_char(direction_str).and_then(Direction::from_letter)?;
+        let (length_str, _) = parse::split_once(s, " ").map_err(|e| e.within(l, s))?;
+        let length = parse::number(length_str).map_err(|e| e.within(l, length_str))?;
+        Ok(Self::new(direction, length))
+    }
+}
+
+/// Reads an edge from the `(#rrrrrd)` colour code at the end of a line.
+fn parse_colour(l: &str) -> Result<Edge, ParseError> {
+    let s = l.rsplit(' ').next().unwrap_or(l);
+    let colour = s
+        .strip_prefix("(#")
+        .and_then(|s| s.strip_suffix(')'))
+        .filter(|c| c.len() == 6 && c.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(|| ParseError::at(l, s, "expected a colour `(#rrggbb)`"))?;
+    let length = usize::from_str_radix(&colour[..5], 16).expect("checked to be hex");
+    let last_digit = &colour[5..];
+    let direction = single_char(last_digit)
+        .and_then(Direction::from_digit)
+        .map_err(|e| e.within(l, last_digit))?;
+    Ok(Edge::new(direction, length))
+}
+
+fn single_char(s: &str) -> Result<char, ParseError> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(ParseError::new("expected a single character", s)),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Vertex {
+    position: Point,
+}
+
+impl Vertex {
+    fn new(position: Point) -> Self {
+        Self { position }
+    }
+}
+
+fn area(vertices: &[Vertex]) -> i64 {
+    let mut area = 0;
+    for i in 0..(vertices.len() - 1) {
+        let v1 = vertices[i];
+        let v2 = vertices[i + 1];
+        area += (v1.position.y + v2.position.y) * (v1.position.x - v2.position.x);
+    }
+    area / 2
+}
+
+fn boundary_length(vertices: &[Vertex]) -> i64 {
+    let v1 = vertices[0];
+    let v2 = vertices[vertices.len() - 1];
+    let mut length = v1.position.manhattan(v2.position);
+    for i in 0..(vertices.len() - 1) {
+        length += vertices[i].position.manhattan(vertices[i + 1].position);
+    }
+    length
+}
+
+fn solve(edges: &[Edge]) -> i64 {
+    let mut vertices = Vec::with_capacity(edges.len() + 1);
+
+    let mut position = Point::default();
+    for edge in edges {
+        position = position.step_by(edge.direction, edge.length as i64);
+        vertices.push(Vertex::new(position));
+    }
+
+    area(&vertices) + boundary_length(&vertices) / 2 + 1
+}
+
+/// The dig plan read both ways: by the direction and length columns, and by the colour codes.
+#[derive(Debug)]
+pub struct DigPlan {
+    edges: Vec<Edge>,
+    colour_edges: Vec<Edge>,
+}
+
+pub fn parse(input: &str) -> Result<DigPlan, ParseError> {
+    let edges = parse::lines(input, Edge::from_str)?;
+    if edges.is_empty() {
+        return Err(ParseError::new("expected at least one edge", input));
+    }
+    let colour_edges = parse::lines(input, parse_colour)?;
+    Ok(DigPlan {
+        edges,
+        colour_edges,
+    })
+}
+
+pub fn part1(plan: &DigPlan) -> i64 {
+    solve(&plan.edges)
+}
+
+pub fn part2(plan: &DigPlan) -> i64 {
+    solve(&plan.colour_edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(part1(&parse(&input).unwrap()), 62);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(part2(&parse(&input).unwrap()), 952408144115);
+    }
+
+    #[test]
+    fn test_empty() {
+        let error = parse("").unwrap_err();
+        assert_eq!(error.message(), "expected at least one edge");
+    }
+}
@@ -2,25 +2,7 @@
 
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Category {
-    X,
-    M,
-    A,
-    S,
-}
-
-impl From<char> for Category {
-    fn from(value: char) -> Self {
-        match value {
-            'x' => Category::X,
-            'm' => Category::M,
-            'a' => Category::A,
-            's' => Category::S,
-            _ => panic!("invalid category"),
-        }
-    }
-}
+use crate::workflow::{Category, Operator, System, Workflow};
 
 #[derive(Debug, Clone, Copy)]
 struct Rating {
@@ -90,86 +72,6 @@ impl Rating {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Operator {
-    LessThan,
-    GreaterThan,
-}
-
-impl From<char> for Operator {
-    fn from(value: char) -> Self {
-        match value {
-            '<' => Operator::LessThan,
-            '>' => Operator::GreaterThan,
-            _ => panic!("invalid operator"),
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy)]
-struct Rule {
-    op: Operator,
-    cutoff: usize,
-    category: Category,
-}
-
-#[derive(Debug)]
-struct WorkflowPart {
-    rule: Option<Rule>,
-    target: String,
-}
-
-impl From<&str> for WorkflowPart {
-    fn from(value: &str) -> Self {
-        if value.contains(":") {
-            let (comparison, target) = value
-                .split_once(":")
-                .expect("failed to split workflow part");
-
-            let mut comparison = comparison.chars();
-
-            let category = Category::from(comparison.next().expect("failed to get category"));
-            let op = Operator::from(comparison.next().expect("failed to get operator"));
-            let cutoff = comparison
-                .as_str()
-                .parse::<usize>()
-                .expect("failed to get cutoff");
-            Self {
-                rule: Some(Rule {
-                    category,
-                    op,
-                    cutoff,
-                }),
-                target: target.to_string(),
-            }
-        } else {
-            Self {
-                rule: None,
-                target: value.to_string(),
-            }
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Workflow {
-    name: String,
-    parts: Vec<WorkflowPart>,
-}
-
-impl From<&str> for Workflow {
-    fn from(value: &str) -> Self {
-        let (name, rest) = value.split_once("{").expect("failed to split workflow");
-        let rest = &rest[0..rest.len() - 1];
-        let parts = rest.split(",").map(WorkflowPart::from).collect();
-
-        Self {
-            name: name.to_owned(),
-            parts,
-        }
-    }
-}
-
 fn next(rating: Rating, workflow_name: &str, workflows: &HashMap<String, Workflow>) -> usize {
     match workflow_name {
         "A" => rating.sum(),
@@ -191,16 +93,16 @@ fn solve(rating: Rating, workflow: &Workflow, workflows: &HashMap<String, Workfl
             if rating.contains(rule.cutoff, rule.category) {
                 match rule.op {
                     Operator::LessThan => {
-                        let mut r1 = rating.clone();
-                        let mut r2 = rating.clone();
+                        let mut r1 = rating;
+                        let mut r2 = rating;
                         r1.set_max(rule.cutoff - 1, rule.category);
                         r2.set_min(rule.cutoff, rule.category);
                         rating = r2;
                         total += next(r1, &part.target, workflows);
                     }
                     Operator::GreaterThan => {
-                        let mut r1 = rating.clone();
-                        let mut r2 = rating.clone();
+                        let mut r1 = rating;
+                        let mut r2 = rating;
                         r1.set_max(rule.cutoff, rule.category);
                         r2.set_min(rule.cutoff + 1, rule.category);
                         rating = r1;
@@ -230,18 +132,8 @@ fn solve(rating: Rating, workflow: &Workflow, workflows: &HashMap<String, Workfl
     total
 }
 
-fn parse_workflows(workflows: &str) -> HashMap<String, Workflow> {
-    workflows
-        .lines()
-        .map(Workflow::from)
-        .map(|w| (w.name.to_string(), w))
-        .collect()
-}
-
-pub fn part2(input: &str) -> usize {
-    let (workflows, _) = input.split_once("\n\n").expect("failed to split input");
-    let workflows = parse_workflows(workflows);
-
+pub fn part2(system: &System) -> usize {
+    let workflows = &system.workflows;
     let start = workflows.get("in").expect("failed to get starting entry");
     let rating = Rating {
         min_x: 1,
@@ -253,7 +145,7 @@ pub fn part2(input: &str) -> usize {
         min_s: 1,
         max_s: 4000,
     };
-    solve(rating, start, &workflows)
+    solve(rating, start, workflows)
 }
 
 #[cfg(test)]
@@ -264,7 +156,7 @@ mod tests {
     #[test]
     fn test_part2() {
         let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
-        let result = part2(&input);
+        let result = part2(&crate::parse(&input).unwrap());
         assert_eq!(result, 167409079868000)
     }
 }
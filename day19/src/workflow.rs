@@ -0,0 +1,284 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{parse, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl TryFrom<char> for Category {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'x' => Ok(Category::X),
+            'm' => Ok(Category::M),
+            'a' => Ok(Category::A),
+            's' => Ok(Category::S),
+            _ => Err(ParseError::new("invalid category", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Rating {
+    pub(crate) x: usize,
+    pub(crate) m: usize,
+    pub(crate) a: usize,
+    pub(crate) s: usize,
+}
+
+impl FromStr for Rating {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let inner = value
+            .strip_prefix('{')
+            .and_then(|v| v.strip_suffix('}'))
+            .ok_or_else(|| ParseError::new("expected `{x=..,m=..,a=..,s=..}`", value))?;
+        let mut rating = [0; 4];
+        let mut categories = inner.split(',');
+        for (expected, n) in ["x", "m", "a", "s"].iter().zip(rating.iter_mut()) {
+            let s = categories
+                .next()
+                .ok_or_else(|| ParseError::at(value, inner, format!("expected `{expected}=`")))?;
+            let (category, number) = parse::split_once(s, "=").map_err(|e| e.within(value, s))?;
+            if category != *expected {
+                return Err(ParseError::at(
+                    value,
+                    category,
+                    format!("expected `{expected}`"),
+                ));
+            }
+            *n = parse::number(number).map_err(|e| e.within(value, number))?;
+        }
+        if let Some(extra) = categories.next() {
+            return Err(ParseError::at(value, extra, "expected `}`"));
+        }
+        let [x, m, a, s] = rating;
+        Ok(Self { x, m, a, s })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operator {
+    LessThan,
+    GreaterThan,
+}
+
+impl TryFrom<char> for Operator {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '<' => Ok(Operator::LessThan),
+            '>' => Ok(Operator::GreaterThan),
+            _ => Err(ParseError::new("invalid operator", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Rule {
+    pub(crate) op: Operator,
+    pub(crate) cutoff: usize,
+    pub(crate) category: Category,
+}
+
+#[derive(Debug)]
+pub(crate) struct WorkflowPart {
+    pub(crate) rule: Option<Rule>,
+    pub(crate) target: String,
+}
+
+impl FromStr for WorkflowPart {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let Some((comparison, target)) = value.split_once(':') else {
+            return Ok(Self {
+                rule: None,
+                target: value.to_string(),
+            });
+        };
+
+        let mut chars = comparison.chars();
+        let category = chars
+            .next()
+            .ok_or_else(|| ParseError::new("expected a category", comparison))
+            .and_then(Category::try_from)?;
+        let op = chars
+            .next()
+            .ok_or_else(|| ParseError::new("expected an operator", comparison))
+            .and_then(Operator::try_from)
+            .map_err(|e| e.within(value, &comparison[1..]))?;
+        let cutoff_str = chars.as_str();
+        let cutoff = parse::number(cutoff_str).map_err(|e| e.within(value, cutoff_str))?;
+        Ok(Self {
+            rule: Some(Rule {
+                category,
+                op,
+                cutoff,
+            }),
+            target: target.to_string(),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Workflow {
+    pub(crate) name: String,
+    pub(crate) parts: Vec<WorkflowPart>,
+}
+
+impl FromStr for Workflow {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (name, rest) = parse::split_once(value, "{")?;
+        let rest = rest
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::at(value, rest, "expected `}`"))?;
+        if rest.is_empty() {
+            return Err(ParseError::at(value, rest, "expected at least one rule"));
+        }
+        let count = rest.split(',').count();
+        let parts = rest
+            .split(',')
+            .enumerate()
+            .map(|(i, p)| {
+                let part = WorkflowPart::from_str(p).map_err(|e| e.within(value, p))?;
+                match (part.rule.is_some(), i + 1 == count) {
+                    (true, true) => Err(ParseError::at(value, p, "expected a fallback rule")),
+                    (false, false) => Err(ParseError::at(value, p, "expected a condition")),
+                    _ => Ok(part),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            name: name.to_owned(),
+            parts,
+        })
+    }
+}
+
+/// The target of each rule in a workflow's line, as it appears in the line.
+fn targets(line: &str) -> impl Iterator<Item = &str> {
+    line.split(['{', ',', '}'])
+        .skip(1)
+        .filter(|part| !part.is_empty())
+        .map(|part| part.split_once(':').map_or(part, |(_, target)| target))
+}
+
+/// The first target, in a depth first walk from `name`, that leads back to a workflow still
+/// being walked, marking each workflow in `done` once everything it reaches has been walked.
+fn find_cycle<'a>(
+    name: &'a str,
+    edges: &HashMap<&'a str, Vec<&'a str>>,
+    done: &mut HashMap<&'a str, bool>,
+) -> Option<&'a str> {
+    done.insert(name, false);
+    for &target in &edges[name] {
+        if matches!(target, "A" | "R") {
+            continue;
+        }
+        match done.get(target) {
+            Some(false) => return Some(target),
+            Some(true) => {}
+            None => {
+                if let Some(cycle) = find_cycle(target, edges, done) {
+                    return Some(cycle);
+                }
+            }
+        }
+    }
+    done.insert(name, true);
+    None
+}
+
+/// The workflows, keyed by name, and the part ratings to sort through them.
+#[derive(Debug)]
+pub struct System {
+    pub(crate) workflows: HashMap<String, Workflow>,
+    pub(crate) ratings: Vec<Rating>,
+}
+
+pub fn parse(input: &str) -> Result<System, ParseError> {
+    let (workflows_str, ratings_str) = parse::split_once(input, "\n\n")?;
+    let parsed = parse::lines(workflows_str, Workflow::from_str)?;
+    let mut workflows = HashMap::new();
+    for (line, workflow) in workflows_str.lines().zip(parsed) {
+        let name = &line[..workflow.name.len()];
+        if workflows.insert(workflow.name.clone(), workflow).is_some() {
+            return Err(ParseError::at(input, name, "duplicate workflow"));
+        }
+    }
+    if !workflows.contains_key("in") {
+        return Err(ParseError::new("expected an `in` workflow", ""));
+    }
+    for target in workflows_str.lines().flat_map(targets) {
+        if !matches!(target, "A" | "R") && !workflows.contains_key(target) {
+            return Err(ParseError::at(input, target, "unknown workflow"));
+        }
+    }
+    let edges: HashMap<&str, Vec<&str>> = workflows_str
+        .lines()
+        .map(|line| {
+            (
+                &line[..line.find('{').unwrap_or(0)],
+                targets(line).collect(),
+            )
+        })
+        .collect();
+    let mut done = HashMap::new();
+    for line in workflows_str.lines() {
+        let name = &line[..line.find('{').unwrap_or(0)];
+        if done.contains_key(name) {
+            continue;
+        }
+        if let Some(target) = find_cycle(name, &edges, &mut done) {
+            return Err(ParseError::at(
+                input,
+                target,
+                "workflow leads back to itself",
+            ));
+        }
+    }
+    let ratings =
+        parse::lines(ratings_str, Rating::from_str).map_err(|e| e.within(input, ratings_str))?;
+    Ok(System { workflows, ratings })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validation() {
+        let error = parse("in{x<5:foo,R}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+        assert_eq!(error.message(), "unknown workflow");
+        assert_eq!((error.line(), error.column()), (1, 8));
+
+        let error = parse("in{A}\nab{R}\nin{R}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+        assert_eq!(error.message(), "duplicate workflow");
+        assert_eq!((error.line(), error.column()), (3, 1));
+
+        assert!(parse("in{x<5:ab,R}\nab{A}\n\n{x=1,m=2,a=3,s=4}\n").is_ok());
+
+        let message = |input: &str| parse(input).unwrap_err().message().to_string();
+        assert_eq!(message("in{}\n\n"), "expected at least one rule");
+        assert_eq!(message("in{x<5:A}\n\n"), "expected a fallback rule");
+        assert_eq!(message("in{A,R}\n\n"), "expected a condition");
+        assert_eq!(message("in{x<5:in,A}\n\n"), "workflow leads back to itself");
+        assert_eq!(
+            message("in{x<5:ab,A}\nab{m>2:cd,R}\ncd{in}\n\n"),
+            "workflow leads back to itself"
+        );
+        assert_eq!(message("in{A}\n\n{x=1,m=2,a=3,s=4,q=1}\n"), "expected `}`");
+    }
+}
@@ -0,0 +1,316 @@
+#![allow(dead_code)]
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+};
+
+use common::{parse, ParseError, Part, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = State;
+    type Params = ();
+    type Answer = Count;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn params(_part: Part) -> Self::Params {}
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        Count(Some(part1(input)))
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer {
+        part2(input)
+    }
+}
+
+/// A count that may never be reached, which is shown as `never`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Count(pub Option<usize>);
+
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(count) => write!(f, "{count}"),
+            None => write!(f, "never"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Pulse {
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone)]
+enum Module {
+    FlipFlop {
+        name: String,
+        on: bool,
+        destinations: Vec<String>,
+    },
+    Conjunction {
+        name: String,
+        memory: HashMap<String, Pulse>,
+        destinations: Vec<String>,
+    },
+    Broadcaster {
+        name: String,
+        destinations: Vec<String>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct State {
+    modules: HashMap<String, Module>,
+    queue: VecDeque<(String, Pulse, String)>,
+    pulse_count: HashMap<Pulse, usize>,
+}
+
+impl State {
+    fn new(modules: HashMap<String, Module>) -> Self {
+        let mut pulse_count = HashMap::new();
+        pulse_count.insert(Pulse::Low, 0);
+        pulse_count.insert(Pulse::High, 0);
+        Self {
+            modules,
+            queue: VecDeque::new(),
+            pulse_count,
+        }
+    }
+
+    /// Sends every queued pulse, returning the modules that sent a high pulse to `watched`.
+    fn process_queue(&mut self, watched: &str) -> Vec<String> {
+        let mut senders = Vec::new();
+        while let Some((producer, pulse, target)) = self.queue.pop_front() {
+            self.pulse_count.entry(pulse).and_modify(|c| *c += 1);
+            if target == watched && pulse == Pulse::High {
+                senders.push(producer.clone());
+            }
+
+            match self.modules.get_mut(&target) {
+                Some(Module::FlipFlop {
+                    on,
+                    destinations,
+                    name,
+                }) => {
+                    match pulse {
+                        Pulse::Low => {
+                            *on = !*on;
+                            for dest in destinations {
+                                if *on {
+                                    self.queue.push_back((
+                                        name.to_string(),
+                                        Pulse::High,
+                                        dest.clone(),
+                                    ));
+                                } else {
+                                    self.queue.push_back((
+                                        name.to_string(),
+                                        Pulse::Low,
+                                        dest.clone(),
+                                    ));
+                                }
+                            }
+                        }
+                        Pulse::High => (),
+                    }
+                    //
+                }
+                Some(Module::Conjunction {
+                    memory,
+                    destinations,
+                    name,
+                }) => {
+                    memory.insert(producer, pulse);
+                    let pulse = if memory.values().all(|p| *p == Pulse::High) {
+                        Pulse::Low
+                    } else {
+                        Pulse::High
+                    };
+                    for dest in destinations {
+                        self.queue
+                            .push_back((name.to_string(), pulse, dest.clone()));
+                    }
+                }
+                Some(Module::Broadcaster { destinations, name }) => {
+                    for dest in destinations {
+                        self.queue
+                            .push_back((name.to_string(), pulse, dest.clone()));
+                    }
+                }
+                // skip unnamed modules
+                None => (),
+            }
+        }
+        senders
+    }
+}
+
+fn parse_module(l: &str) -> Result<Module, ParseError> {
+    let (name, dests) = parse::split_once(l, " -> ")?;
+    let destinations = dests.split(", ").map(|s| s.to_string()).collect();
+    let module = if let Some(name) = name.strip_prefix('%') {
+        Module::FlipFlop {
+            name: name.to_string(),
+            on: false,
+            destinations,
+        }
+    } else if let Some(name) = name.strip_prefix('&') {
+        Module::Conjunction {
+            name: name.to_string(),
+            memory: HashMap::new(),
+            destinations,
+        }
+    } else if name == "broadcaster" {
+        Module::Broadcaster {
+            name: name.to_string(),
+            destinations,
+        }
+    } else {
+        return Err(ParseError::new("expected `%`, `&` or `broadcaster`", name));
+    };
+    Ok(module)
+}
+
+impl Module {
+    fn name(&self) -> &str {
+        match self {
+            Module::FlipFlop { name, .. }
+            | Module::Conjunction { name, .. }
+            | Module::Broadcaster { name, .. } => name,
+        }
+    }
+
+    fn destinations(&self) -> &[String] {
+        match self {
+            Module::FlipFlop { destinations, .. }
+            | Module::Conjunction { destinations, .. }
+            | Module::Broadcaster { destinations, .. } => destinations,
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<State, ParseError> {
+    let mut targeting: HashMap<String, Vec<String>> = HashMap::new();
+    let mut modules: HashMap<_, _> = parse::lines(input, parse_module)?
+        .into_iter()
+        .map(|module| {
+            for dest in module.destinations() {
+                targeting
+                    .entry(dest.clone())
+                    .or_default()
+                    .push(module.name().to_string());
+            }
+            (module.name().to_string(), module)
+        })
+        .collect();
+
+    if !modules.contains_key("broadcaster") {
+        return Err(ParseError::new("expected a `broadcaster` module", ""));
+    }
+
+    for (name, module) in modules.iter_mut() {
+        match module {
+            Module::FlipFlop { .. } | Module::Broadcaster { .. } => (),
+            Module::Conjunction { memory, .. } => {
+                for t in targeting.get(name).into_iter().flatten() {
+                    memory.insert(t.clone(), Pulse::Low);
+                }
+            }
+        }
+    }
+    Ok(State::new(modules))
+}
+
+pub fn part1(state: &State) -> usize {
+    let mut state = state.clone();
+    for _ in 0..1000 {
+        state
+            .queue
+            .push_back(("button".to_string(), Pulse::Low, "broadcaster".to_string()));
+        state.process_queue("");
+    }
+    let high_count = *state
+        .pulse_count
+        .get(&Pulse::High)
+        .expect("failed to get pulse count");
+    let low_count = *state
+        .pulse_count
+        .get(&Pulse::Low)
+        .expect("failed to get pulse count");
+    high_count * low_count
+}
+
+/// The presses until `rx` gets a low pulse, or `None` if it isn't fed by a conjunction.
+///
+/// The conjunction feeding `rx` only sends it a low pulse once each of its inputs has sent
+/// it a high one in the same press, and each input does so every so many presses, so the
+/// answer is the product of those cycle lengths.
+pub fn part2(state: &State) -> Count {
+    let feeder = state
+        .modules
+        .values()
+        .find(|module| module.destinations().iter().any(|dest| dest == "rx"));
+    let Some(Module::Conjunction { name, memory, .. }) = feeder else {
+        return Count(None);
+    };
+    let (feeder, inputs) = (name.clone(), memory.len());
+    let mut state = state.clone();
+    let mut cycle_lengths = HashMap::new();
+    for presses in 1.. {
+        state
+            .queue
+            .push_back(("button".to_string(), Pulse::Low, "broadcaster".to_string()));
+        for sender in state.process_queue(&feeder) {
+            cycle_lengths.entry(sender).or_insert(presses);
+        }
+        if cycle_lengths.len() == inputs {
+            break;
+        }
+    }
+    Count(Some(cycle_lengths.values().product()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(part1(&parse_input(&input).unwrap()), 32000000);
+
+        let input = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+        assert_eq!(part1(&parse_input(input).unwrap()), 11687500);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(part2(&parse_input(&input).unwrap()), Count(None));
+
+        // `b` is first turned on, sending `con` a high pulse, by the second press
+        let input = "broadcaster -> a
+%a -> b
+%b -> con
+&con -> rx";
+        assert_eq!(part2(&parse_input(input).unwrap()), Count(Some(2)));
+
+        let error = parse_input("%a -> b\n").unwrap_err();
+        assert_eq!(error.message(), "expected a `broadcaster` module");
+    }
+}
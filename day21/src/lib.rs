@@ -0,0 +1,197 @@
+#![allow(dead_code)]
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    str::FromStr,
+};
+
+use common::{geometry::Point, ParseError, Part, Solution};
+use grid::{Cell, Direction, Grid, Position};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = Map;
+    type Params = usize;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    /// The number of steps the elf takes.
+    fn params(part: Part) -> Self::Params {
+        match part {
+            Part::One => 64,
+            Part::Two => 26501365,
+        }
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+        part1(input, *params)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Self::Answer {
+        part2(input, *params)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Ground {
+    Garden,
+    Rock,
+}
+
+impl Cell for Ground {
+    fn from_char(value: char) -> Result<Self, ParseError> {
+        match value {
+            '.' => Ok(Self::Garden),
+            '#' => Ok(Self::Rock),
+            _ => Err(ParseError::new("invalid ground", value)),
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Self::Garden => '.',
+            Self::Rock => '#',
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    ground: Grid<Ground>,
+    start_position: Position,
+}
+
+impl Map {
+    /// Draws the map with each of `positions` marked `O`.
+    fn render(&self, positions: &HashSet<Position>) -> String {
+        let mut map = self.ground.map(Ground::to_char);
+        for &position in positions {
+            map[position] = 'O';
+        }
+        map.to_string()
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let ground = Grid::parse_with(value, |c| match c {
+            'S' => Ok(Ground::Garden),
+            _ => Ground::from_char(c),
+        })?;
+        let start_position = value
+            .lines()
+            .enumerate()
+            .find_map(|(y, l)| Some(Position::new(l.chars().position(|c| c == 'S')?, y)))
+            .ok_or_else(|| ParseError::new("expected a starting position `S`", ""))?;
+        Ok(Self {
+            ground,
+            start_position,
+        })
+    }
+}
+
+fn step(map: &Map, positions: &HashSet<Position>) -> HashSet<Position> {
+    positions
+        .iter()
+        .flat_map(|&position| map.ground.neighbours4(position))
+        .filter(|&position| map.ground[position] == Ground::Garden)
+        .collect()
+}
+
+pub fn part1(map: &Map, steps: usize) -> usize {
+    let mut possible_positions = HashSet::new();
+    possible_positions.insert(map.start_position);
+    for _ in 0..steps {
+        possible_positions = step(map, &possible_positions);
+    }
+    possible_positions.len()
+}
+
+/// Counts the plots reachable in exactly each of `steps` steps on a map that repeats
+/// infinitely in every direction.
+fn reachable_infinite(map: &Map, steps: &[usize]) -> Vec<usize> {
+    let max_steps = steps.iter().copied().max().unwrap_or(0);
+    let start = Point::from(map.start_position);
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    distances.insert(start, 0);
+    queue.push_back((start, 0));
+    while let Some((point, distance)) = queue.pop_front() {
+        if distance == max_steps {
+            continue;
+        }
+        for direction in Direction::ALL {
+            let next = point.step(direction);
+            let position = Position::new(
+                next.x.rem_euclid(map.ground.width() as i64) as usize,
+                next.y.rem_euclid(map.ground.height() as i64) as usize,
+            );
+            if map.ground[position] == Ground::Rock || distances.contains_key(&next) {
+                continue;
+            }
+            distances.insert(next, distance + 1);
+            queue.push_back((next, distance + 1));
+        }
+    }
+    steps
+        .iter()
+        .map(|&s| {
+            distances
+                .values()
+                .filter(|&&d| d <= s && d % 2 == s % 2)
+                .count()
+        })
+        .collect()
+}
+
+/// The start sits on an empty row and column of a square map, so the number of reachable
+/// plots grows quadratically with each whole map crossed. Three samples are enough to fit it.
+pub fn part2(map: &Map, steps: usize) -> usize {
+    let size = map.ground.width();
+    let offset = steps % size;
+    let samples = reachable_infinite(map, &[offset, offset + size, offset + 2 * size]);
+    let (a, b, c) = (samples[0], samples[1], samples[2]);
+    let n = steps / size;
+    a + n * (b - a) + n * n.saturating_sub(1) / 2 * (c + a - 2 * b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part1() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        assert_eq!(part1(&input.parse::<Map>().unwrap(), 6), 16);
+    }
+
+    #[test]
+    fn test_render() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        let map = input.parse::<Map>().unwrap();
+        let positions = step(&map, &HashSet::from([map.start_position]));
+        let rendered = map.render(&positions);
+        let rows: Vec<_> = rendered.lines().collect();
+        assert_eq!(rows[4], "....#O#....");
+        assert_eq!(rows[5], ".##.O.####.");
+    }
+
+    #[test]
+    fn test_reachable_infinite() {
+        let input = fs::read_to_string("test_input.txt").expect("failed to read test input file");
+        let map = input.parse::<Map>().unwrap();
+        assert_eq!(
+            reachable_infinite(&map, &[6, 10, 50, 100]),
+            vec![16, 50, 1594, 6536]
+        );
+    }
+}
@@ -0,0 +1,300 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use common::{parse, ParseError};
+
+pub use common::geometry::{Direction, Position};
+
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A grid cell that is read from, and drawn as, a single character.
+pub trait Cell: Sized {
+    fn from_char(c: char) -> Result<Self, ParseError>;
+
+    fn to_char(&self) -> char;
+}
+
+impl Cell for char {
+    fn from_char(c: char) -> Result<Self, ParseError> {
+        Ok(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// A rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Builds a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        assert!(
+            rows.iter().all(|r| r.len() == width),
+            "grid rows must be the same length"
+        );
+        Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        }
+    }
+
+    /// Parses each character of `input` with `f`.
+    pub fn parse_with(
+        input: &str,
+        f: impl FnMut(char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        Ok(Self::from_rows(parse::grid(input, f)?))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.y * self.width + position.x])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if !self.contains(position) {
+            return None;
+        }
+        Some(&mut self.cells[position.y * self.width + position.x])
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position, row by row, whose cell matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter().find(|(_, c)| predicate(c)).map(|(p, _)| p)
+    }
+
+    /// Moves `position` by `dx` and `dy`, if that stays inside the grid.
+    pub fn offset(&self, position: Position, dx: isize, dy: isize) -> Option<Position> {
+        let x = position.x.checked_add_signed(dx)?;
+        let y = position.y.checked_add_signed(dy)?;
+        let position = Position::new(x, y);
+        self.contains(position).then_some(position)
+    }
+
+    /// Moves `position` one step in `direction`, if that stays inside the grid.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        position.step_within(direction, self.width, self.height)
+    }
+
+    /// The positions above, right of, below and left of `position` that are in the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The positions surrounding `position`, including diagonals, that are in the grid.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        SURROUNDING
+            .iter()
+            .filter_map(move |&(dx, dy)| self.offset(position, dx, dy))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a zero width, which an empty grid would have
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Swaps rows and columns, mirroring the grid along its main diagonal.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: self.columns().flatten().cloned().collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    /// Rotates the grid a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: self
+                .columns()
+                .flat_map(|c| c.collect::<Vec<_>>().into_iter().rev())
+                .cloned()
+                .collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise, so the top row becomes the left
+    /// column.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|x| self.column(x))
+                .cloned()
+                .collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+impl<T: Cell> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(input, T::from_char)
+    }
+}
+
+impl<T: Cell> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> Grid<char> {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let input = "ab.\n#cd";
+        let g = grid(input);
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g[Position::new(2, 1)], 'd');
+        assert_eq!(g.get(Position::new(3, 0)), None);
+        assert_eq!(g.to_string(), input);
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let g = grid("...\n...\n...");
+        let corner: Vec<_> = g.neighbours4(Position::new(0, 0)).collect();
+        assert_eq!(corner, vec![Position::new(1, 0), Position::new(0, 1)]);
+        assert_eq!(g.neighbours8(Position::new(0, 0)).count(), 3);
+        assert_eq!(g.neighbours4(Position::new(1, 1)).count(), 4);
+        assert_eq!(g.neighbours8(Position::new(1, 1)).count(), 8);
+        assert_eq!(g.neighbours8(Position::new(2, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_views() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.row(1), &['d', 'e', 'f']);
+        assert_eq!(g.column(1).collect::<String>(), "be");
+        assert_eq!(g.columns().count(), 3);
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(g.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(g.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(g.rotate_clockwise().rotate_counterclockwise(), g);
+        assert_eq!(g.position(|&c| c == 'e'), Some(Position::new(1, 1)));
+    }
+}